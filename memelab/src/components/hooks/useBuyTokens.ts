@@ -8,31 +8,51 @@ import {
 } from "@solana/spl-token";
 import { deriveAssociatedTokenAddress } from '@/lib/utils'
 import { toast } from "sonner";
-import type { ProgramLike } from "../../lib/anchorTypes";
+import type { ProgramLike, TokenBondingCurveAccount } from "../../lib/anchorTypes";
 import { getErrorMessage } from "../../lib/utils";
 
-// 1. SAFER SETUP: Load from Environment Variable
-// We use a fallback (System Program ID) just to prevent the app from crashing 
-// if you forget to set the .env file, but you MUST set it to receive fees.
-const PLATFORM_WALLET_STRING = process.env.NEXT_PUBLIC_PLATFORM_FEE_WALLET || "11111111111111111111111111111111";
-const PLATFORM_WALLET_ADDRESS = new PublicKey(PLATFORM_WALLET_STRING);
+// Default slippage tolerance for min_tokens_out (1%)
+const DEFAULT_SLIPPAGE_BPS = 100;
+
+// Mirrors calculate_tokens_out in the program's maths.rs
+const tokensOut = (solIn: BN, virtualSol: BN, virtualToken: BN) =>
+  virtualToken.sub(virtualSol.mul(virtualToken).div(virtualSol.add(solIn)));
+
+// Mirrors current_sniper_fee_bps on TokenBondingCurve
+const sniperFeeBps = (curve: TokenBondingCurveAccount, now: number) => {
+  const elapsed = now - curve.tradingLiveAt.toNumber();
+  const duration = curve.sniperFeeDurationSeconds.toNumber();
+  if (curve.tradingLive === 0 || elapsed >= duration) return 0;
+  return Math.floor((curve.sniperFeeBps * (duration - elapsed)) / duration);
+};
+
+// Expected tokens for a buy of `lamports`, following buy_tokens: fees come off
+// the top and the fill stops at the curve's completion thresholds.
+const quoteBuy = (curve: TokenBondingCurveAccount, platformFeeBps: number, lamports: BN) => {
+  const feeBps = platformFeeBps + curve.creatorFeeBps + sniperFeeBps(curve, Math.floor(Date.now() / 1000));
+  const solIn = lamports.sub(lamports.muln(feeBps).divn(10_000));
+  let quote = tokensOut(solIn, curve.virtualSolReserves, curve.virtualTokenReserves);
+
+  if (!curve.completionSolThreshold.isZero()) {
+    const solRoom = BN.max(curve.completionSolThreshold.sub(curve.realSolReserves), new BN(0));
+    if (solIn.gte(solRoom)) {
+      quote = BN.min(quote, tokensOut(solRoom, curve.virtualSolReserves, curve.virtualTokenReserves));
+    }
+  }
+  const tokenRoom = BN.max(curve.realTokenReserves.sub(curve.completionTokenThreshold), new BN(0));
+  return BN.min(quote, tokenRoom);
+};
 
 export const useBuyTokens = () => {
   const { program } = useMemelabProgram();
   const { publicKey } = useWallet();
 
-  const buy = async (mintAddress: string, amountSol: number) => {
-    // 2. Safety Checks
+  const buy = async (mintAddress: string, amountSol: number, slippageBps = DEFAULT_SLIPPAGE_BPS) => {
     if (!program || !publicKey) {
         toast("Wallet not connected");
         return;
     }
 
-    // Check if the developer forgot to set the fee wallet
-    if (PLATFORM_WALLET_STRING === "11111111111111111111111111111111") {
-        console.warn("⚠️ WARNING: Platform Fee Wallet is not set in .env.local!");
-    }
-
     try {
       toast("Buying tokens...");
       
@@ -63,9 +83,13 @@ export const useBuyTokens = () => {
       console.log("Fetching bonding curve data...");
       const p = program as unknown as ProgramLike;
       const curveState = await p.account.tokenBondingCurve.fetch(bondingCurve);
-      const creatorWallet = curveState.creator;
+      const config = await p.account.platformConfig.fetch(platformConfig);
+
+      // Revert instead of filling at a worse price than quoted
+      const quote = quoteBuy(curveState, config.platformFeeBps, amountLamports);
+      const minTokensOut = quote.muln(10_000 - slippageBps).divn(10_000);
 
-      const tx = await p.methods.buyTokens!(amountLamports)
+      const tx = await p.methods.buyTokens!(amountLamports, minTokensOut)
         .accounts({
           buyer: publicKey,
           bondingCurve: bondingCurve,
@@ -73,13 +97,10 @@ export const useBuyTokens = () => {
           platformConfig: platformConfig,
           bondingCurveTokenAccount: bondingCurveVault,
           buyerTokenAccount: userTokenAccount,
-          
-          // 3. Use the Environment Variable Address
-          platformFeeWallet: PLATFORM_WALLET_ADDRESS,
-          
-          // 4. Use the Dynamic Creator Address
-          creatorFeeWallet: creatorWallet,
-          
+          // No referrer or volume tier: the flat platform fee applies
+          referrer: null,
+          feeSchedule: null,
+          traderVolume: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
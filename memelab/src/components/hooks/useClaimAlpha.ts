@@ -12,7 +12,8 @@ export const useClaimAlpha = () => {
   const { program } = useMemelabProgram();
   const { publicKey } = useWallet();
 
-  const claimAlpha = async (mintAddress: string) => {
+  // closeDeposit reclaims the deposit account's rent once the claim is complete
+  const claimAlpha = async (mintAddress: string, closeDeposit = false) => {
     if (!program || !publicKey) {
       toast.error("Wallet not connected");
       return;
@@ -23,6 +24,11 @@ export const useClaimAlpha = () => {
 
       const mint = new PublicKey(mintAddress);
 
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+
       // 1. Bonding Curve PDA
       const [bondingCurve] = PublicKey.findProgramAddressSync(
         [Buffer.from("bonding_curve"), mint.toBuffer()],
@@ -51,11 +57,12 @@ export const useClaimAlpha = () => {
 
       // Execute Transaction
       const tx = await program.methods
-        .claimAlpha()
+        .claimAlpha(closeDeposit)
         .accounts({
           claimer: publicKey,
           mint: mint,
           bondingCurve: bondingCurve,
+          platformConfig: platformConfig,
           
           // ✅ FIX: Use the correct account name from Rust ('user_deposit' -> 'userDeposit')
           userDeposit: userDeposit, 
@@ -8,14 +8,51 @@ import {
 } from "@solana/spl-token";
 import { toast } from "sonner";
 import { deriveAssociatedTokenAddress } from "@/lib/utils";
+import type { ProgramLike } from "../../lib/anchorTypes";
 
 const MPL_TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 
+// Launch settings; anything left out uses an open, unvested vault with no sniper fee
+export type CreateTokenOptions = {
+  creatorFeeBps?: number;
+  alphaDurationSeconds?: number;
+  alphaVault?: Partial<{
+    startTime: BN;
+    withdrawPenaltyBps: number;
+    minRaise: BN;
+    hardCap: BN;
+    minDeposit: BN;
+    maxDeposit: BN;
+    merkleRoot: number[];
+    vestingCliffSeconds: BN;
+    vestingDurationSeconds: BN;
+  }>;
+  sniperFee?: {
+    feeBps: number;
+    durationSeconds: BN;
+    destination: { creator: {} } | { platform: {} } | { curve: {} };
+  };
+};
+
+const DEFAULT_ALPHA_VAULT = {
+  startTime: new BN(0),
+  withdrawPenaltyBps: 0,
+  minRaise: new BN(0),
+  hardCap: new BN(0),
+  minDeposit: new BN(0),
+  maxDeposit: new BN(0),
+  merkleRoot: Array(32).fill(0),
+  vestingCliffSeconds: new BN(0),
+  vestingDurationSeconds: new BN(0),
+};
+
+const NO_SNIPER_FEE = { feeBps: 0, durationSeconds: new BN(0), destination: { creator: {} } };
+
 export const useCreateToken = () => {
   const { program } = useMemelabProgram();
   const { publicKey } = useWallet();
 
-  const createToken = async (name: string, symbol: string, uri: string) => {
+  const createToken = async (name: string, symbol: string, uri: string, options: CreateTokenOptions = {}) => {
     console.log("🟢 Step 1: createToken function started");
 
     if (!publicKey) {
@@ -38,7 +75,7 @@ export const useCreateToken = () => {
 
       // Generate Mint
       const mintKeypair = Keypair.generate();
-      const alphaDuration = new BN(300); 
+      const alphaDuration = new BN(options.alphaDurationSeconds ?? 300);
       console.log("🟢 Step 3: Mint Generated:", mintKeypair.publicKey.toBase58());
 
       // Derive Addresses
@@ -67,15 +104,27 @@ export const useCreateToken = () => {
         ],
         MPL_TOKEN_METADATA_PROGRAM_ID
       );
+
+      // The creation fee goes to whichever wallet the platform config names
+      const config = await (program as unknown as ProgramLike).account.platformConfig.fetch(platformConfig);
       
       console.log("🟢 Step 5: Addresses Found. Building Transaction...");
 
       // BUILD THE TRANSACTION
       const txBuilder = program.methods
-        .createToken(name, symbol, uri, alphaDuration)
+        .createToken(
+          name,
+          symbol,
+          uri,
+          options.creatorFeeBps ?? 0,
+          alphaDuration,
+          { ...DEFAULT_ALPHA_VAULT, ...options.alphaVault },
+          options.sniperFee ?? NO_SNIPER_FEE
+        )
         .accounts({
           creator: publicKey,
           platformConfig: platformConfig,
+          platformFeeWallet: config.platformFeeWallet,
           mint: mintKeypair.publicKey,
           bondingCurve: bondingCurve,
           bondingCurveTokenAccount: bondingCurveTokenAccount,
@@ -4,11 +4,21 @@ import { BN } from "@coral-xyz/anchor";
 import { useWallet } from "@solana/wallet-adapter-react";
 import { toast } from "sonner";
 
+// Merkle proof for allowlisted launches: the leaf's cap (0 = none) and sibling hashes
+export type AllowlistProof = {
+  maxAmount: BN;
+  proof: number[][];
+};
+
 export const useDepositAlpha = () => {
   const { program } = useMemelabProgram();
   const { publicKey } = useWallet();
 
-  const depositAlpha = async (mintAddress: string, amountSol: number) => {
+  const depositAlpha = async (
+    mintAddress: string,
+    amountSol: number,
+    allowlistProof: AllowlistProof | null = null // Only needed when the vault has a merkle root
+  ) => {
     if (!program || !publicKey) {
       toast.error("Wallet not connected");
       return;
@@ -20,6 +30,11 @@ export const useDepositAlpha = () => {
       const mint = new PublicKey(mintAddress);
       const amountLamports = new BN(Math.floor(amountSol * 1_000_000_000));
 
+      const [platformConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("platform_config")],
+        program.programId
+      );
+
       const [bondingCurve] = PublicKey.findProgramAddressSync(
         [Buffer.from("bonding_curve"), mint.toBuffer()],
         program.programId
@@ -36,11 +51,12 @@ export const useDepositAlpha = () => {
       );
 
       const tx = await program.methods
-        .depositAlpha(amountLamports)
+        .depositAlpha(amountLamports, allowlistProof)
         .accounts({
           user: publicKey,           // Check if Rust calls this 'user' or 'depositor'
           mint: mint,
           bondingCurve: bondingCurve,
+          platformConfig: platformConfig,
           
           // ✅ FIX: Ensure this matches your deposit.rs (likely 'userDeposit' or 'userAlphaDeposit')
           userDeposit: userDeposit, 
@@ -9,10 +9,6 @@ import {
 import { deriveAssociatedTokenAddress } from '@/lib/utils'
 import { toast } from "sonner";
 
-// Use environment variable for safety
-const PLATFORM_WALLET_STRING = process.env.NEXT_PUBLIC_PLATFORM_FEE_WALLET || "11111111111111111111111111111111";
-const PLATFORM_WALLET = new PublicKey(PLATFORM_WALLET_STRING);
-
 export const useSellTokens = () => {
   const { program } = useMemelabProgram();
   const { publicKey } = useWallet();
@@ -54,10 +50,6 @@ export const useSellTokens = () => {
         publicKey
       );
 
-      // --- FETCH CREATOR ---
-      const curveAccount = await (program.account as any).tokenBondingCurve.fetch(bondingCurve);
-      const creatorWallet = curveAccount.creator;
-
       // --- EXECUTE SELL ---
       const tx = await program.methods
         .sellTokens(amountRaw, minSolOut) // Note: If this fails, try removing minSolOut
@@ -68,11 +60,12 @@ export const useSellTokens = () => {
           bondingCurveTokenAccount: bondingCurveTokenAccount,
           sellerTokenAccount: sellerTokenAccount,
           platformConfig: platformConfig,
-          
-          // FEES
-          feeWallet: PLATFORM_WALLET, 
-          creatorWallet: creatorWallet,    // Matched to your Buy hook
-          
+
+          // Fees accrue on the curve; no referrer or volume tier
+          referrer: null,
+          feeSchedule: null,
+          traderVolume: null,
+
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
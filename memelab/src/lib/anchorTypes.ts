@@ -1,7 +1,25 @@
 import { PublicKey } from "@solana/web3.js";
+import type { BN } from "@coral-xyz/anchor";
 
 export type TokenBondingCurveAccount = {
   creator: PublicKey;
+  virtualSolReserves: BN;
+  virtualTokenReserves: BN;
+  realSolReserves: BN;
+  realTokenReserves: BN;
+  completionSolThreshold: BN;
+  completionTokenThreshold: BN;
+  tradingLive: number;
+  tradingLiveAt: BN;
+  creatorFeeBps: number;
+  sniperFeeBps: number;
+  sniperFeeDurationSeconds: BN;
+  [key: string]: unknown;
+};
+
+export type PlatformConfigAccount = {
+  platformFeeWallet: PublicKey;
+  platformFeeBps: number;
   [key: string]: unknown;
 };
 
@@ -14,6 +32,7 @@ export type ProgramLike = {
   };
   account: {
     tokenBondingCurve: { fetch: (pda: PublicKey) => Promise<TokenBondingCurveAccount> };
+    platformConfig: { fetch: (pda: PublicKey) => Promise<PlatformConfigAccount> };
     [key: string]: unknown;
   };
 };
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 #[error_code]
+// Clients match on numeric codes: add new variants at the end, never in between
 pub enum ErrorCode {
     // Admin & Config Errors
     #[msg("Fee cannot be more than 10% (1000 basis points)")]
@@ -131,4 +132,94 @@ pub enum ErrorCode {
 
     #[msg("Creator fee exceeds maximum allowed")]
     CreatorFeeTooHigh,
+
+    #[msg("No accrued fees to claim")]
+    NoFeesToClaim,
+
+    #[msg("Referral share cannot exceed 100% of the platform fee")]
+    ReferralFeeTooHigh,
+
+    #[msg("Traders cannot refer themselves")]
+    SelfReferral,
+
+    #[msg("Anti-sniper fee exceeds maximum allowed")]
+    SniperFeeTooHigh,
+
+    #[msg("Anti-sniper fee window is negative or longer than allowed")]
+    InvalidSniperFeeDuration,
+
+    #[msg("Fee tiers must be ascending by volume with non-increasing fees, within the tier limit and fee cap")]
+    InvalidFeeSchedule,
+
+    // Pool Errors
+    #[msg("Bonding curve is not complete yet")]
+    CurveNotComplete,
+
+    #[msg("Bonding curve has already been migrated")]
+    AlreadyMigrated,
+
+    #[msg("Insufficient liquidity in pool")]
+    InsufficientLiquidity,
+
+    // Alpha Vault Errors (continued)
+    #[msg("Withdrawal exceeds your alpha deposit")]
+    WithdrawExceedsDeposit,
+
+    #[msg("Early withdrawal penalty too high (max 50%)")]
+    WithdrawPenaltyTooHigh,
+
+    #[msg("Alpha Vault did not reach its minimum raise - launch failed")]
+    AlphaMinRaiseNotMet,
+
+    #[msg("Alpha Vault reached its minimum raise - claim instead of refund")]
+    AlphaRaiseNotFailed,
+
+    #[msg("You have already been refunded")]
+    AlreadyRefunded,
+
+    #[msg("Alpha hard cap must be at least the minimum raise")]
+    InvalidAlphaHardCap,
+
+    #[msg("Alpha deposit is below the per-wallet minimum")]
+    DepositBelowMinimum,
+
+    #[msg("Alpha deposit exceeds the per-wallet maximum")]
+    DepositAboveMaximum,
+
+    #[msg("Per-wallet maximum deposit must be at least the minimum")]
+    InvalidDepositLimits,
+
+    #[msg("Wallet is not on this token's alpha allowlist")]
+    NotAllowlisted,
+
+    #[msg("Alpha allowlist can only be changed before the alpha phase starts")]
+    AllowlistLocked,
+
+    #[msg("Vesting cliff and duration must be non-negative")]
+    InvalidVestingSchedule,
+
+    #[msg("No tokens have vested since your last claim")]
+    TokensStillVesting,
+
+    #[msg("Alpha deposit must be fully claimed or refunded before closing")]
+    DepositNotSettled,
+
+    #[msg("Remaining accounts must be (deposit, wallet, token account) triples")]
+    InvalidRemainingAccounts,
+
+    #[msg("The Alpha Vault has not opened for deposits yet")]
+    AlphaPhaseNotStarted,
+
+    #[msg("Alpha start time cannot be in the past")]
+    InvalidAlphaStartTime,
+
+    // Pause & Authority Errors
+    #[msg("No platform authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Platform is paused")]
+    PlatformPaused,
+
+    #[msg("Trading for this token is paused")]
+    TokenPaused,
 }
@@ -0,0 +1,325 @@
+use anchor_lang::prelude::*;
+
+use crate::state::FeeTier;
+
+#[event]
+pub struct TokenCreated {
+    pub mint: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub creation_fee: u64,
+    pub total_supply: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub alpha_phase_start_time: i64,
+    pub alpha_phase_end_time: i64,
+    pub alpha_withdraw_penalty_bps: u16,
+    pub alpha_min_raise: u64,
+    pub alpha_hard_cap: u64,
+    pub alpha_min_deposit: u64,
+    pub alpha_max_deposit: u64,
+    pub alpha_merkle_root: [u8; 32],
+    pub alpha_vesting_cliff_seconds: i64,
+    pub alpha_vesting_duration_seconds: i64,
+    pub creator_fee_bps: u16,
+    pub sniper_fee_bps: u16,
+    pub sniper_fee_duration_seconds: i64,
+    pub sniper_fee_destination: u8,
+    pub completion_sol_threshold: u64,
+    pub completion_token_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaAllowlistUpdated {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub old_merkle_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaDeposited {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub sol_amount: u64,
+    pub user_total_sol: u64,
+    pub total_alpha_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaWithdrawn {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub sol_amount: u64,
+    pub penalty: u64,
+    pub user_total_sol: u64,
+    pub total_alpha_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaFinalized {
+    pub mint: Pubkey,
+    pub total_alpha_sol: u64,
+    pub alpha_sol_used: u64,
+    pub total_alpha_token: u64,
+    pub alpha_clearing_price: u64,
+
+    // Post-finalization curve state
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub is_complete: bool,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaRaiseFailed {
+    pub mint: Pubkey,
+    pub total_alpha_sol: u64,
+    pub alpha_min_raise: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaRefunded {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub sol_amount: u64,
+    pub penalty_refunded: u64,  // included in sol_amount
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaClaimed {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub sol_deposited: u64,
+    pub token_amount: u64,
+    pub total_claimed: u64,
+    pub total_allocation: u64,
+    pub sol_refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AlphaDepositClosed {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Trade {
+    pub mint: Pubkey,
+    pub trader: Pubkey,
+    pub is_buy: bool,
+
+    // SOL into (buy) or out of (sell) the curve, before fees are added/taken
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub platform_fee: u64,
+    pub creator_fee: u64,
+    pub referral_fee: u64,
+    pub sniper_fee: u64,
+
+    // Post-trade curve state
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub is_complete: bool,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorFeesClaimed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformFeesClaimed {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub platform_fee_wallet: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub referrer: Pubkey,
+    pub wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformConfigUpdated {
+    pub authority: Pubkey,
+
+    // New values
+    pub platform_fee_wallet: Pubkey,
+    pub platform_fee_bps: u16,
+    pub max_creator_fee_bps: u16,
+    pub referral_fee_bps: u16,
+    pub max_sniper_fee_bps: u16,
+    pub max_sniper_fee_duration_seconds: i64,
+    pub platform_token_creation_fee: u64,
+    pub completion_sol_threshold: u64,
+    pub completion_token_threshold: u64,
+
+    // Previous values
+    pub old_platform_fee_wallet: Pubkey,
+    pub old_platform_fee_bps: u16,
+    pub old_max_creator_fee_bps: u16,
+    pub old_referral_fee_bps: u16,
+    pub old_max_sniper_fee_bps: u16,
+    pub old_max_sniper_fee_duration_seconds: i64,
+    pub old_platform_token_creation_fee: u64,
+    pub old_completion_sol_threshold: u64,
+    pub old_completion_token_threshold: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeScheduleUpdated {
+    pub authority: Pubkey,
+    pub tiers: Vec<FeeTier>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformInitialized {
+    pub authority: Pubkey,
+    pub platform_fee_wallet: Pubkey,
+    pub platform_fee_bps: u16,
+    pub max_creator_fee_bps: u16,
+    pub max_sniper_fee_bps: u16,
+    pub max_sniper_fee_duration_seconds: i64,
+    pub platform_token_creation_fee: u64,
+    pub completion_sol_threshold: u64,
+    pub completion_token_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub authority: Pubkey,
+    pub mint: Pubkey,           // Pubkey::default() = the platform-wide switch
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub platform_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferAccepted {
+    pub old_authority: Pubkey,
+    pub platform_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub platform_authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CurveMigrated {
+    pub mint: Pubkey,
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+
+    // Moved from the curve into the pool
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub locked_lp_supply: u64,
+    pub pool_fee_bps: u16,
+
+    // Post-migration curve state
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolSwapped {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub is_buy: bool,
+
+    // SOL in and tokens out on a buy, tokens in and SOL out on a sell
+    pub amount_in: u64,
+    pub amount_out: u64,
+
+    // Post-swap pool state
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityAdded {
+    pub mint: Pubkey,
+    pub provider: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub lp_amount: u64,
+
+    // Post-deposit pool state (lp_supply includes locked shares)
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    pub lp_supply: u64,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityRemoved {
+    pub mint: Pubkey,
+    pub provider: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub lp_amount: u64,
+
+    // Post-withdrawal pool state (lp_supply includes locked shares)
+    pub sol_reserves: u64,
+    pub token_reserves: u64,
+    pub lp_supply: u64,
+
+    pub timestamp: i64,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{PlatformConfig, TokenBondingCurve};
+use crate::errors::ErrorCode;
+use crate::events::PlatformFeesClaimed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimPlatformFees<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.platform_authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    /// CHECK: Platform fee wallet - validated by address constraint
+    #[account(
+        mut,
+        address = platform_config.platform_fee_wallet @ ErrorCode::InvalidFeeWallet
+    )]
+    pub platform_fee_wallet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn claim_platform_fees(ctx: Context<ClaimPlatformFees>) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+
+    let amount = bonding_curve.accrued_platform_fees;
+    require!(amount > 0, ErrorCode::NoFeesToClaim);
+
+    bonding_curve.accrued_platform_fees = 0;
+
+    let platform_fees_claimed = PlatformFeesClaimed {
+        mint: bonding_curve.token_mint,
+        authority: ctx.accounts.authority.key(),
+        platform_fee_wallet: ctx.accounts.platform_fee_wallet.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    // Drop before moving lamports
+    drop(bonding_curve);
+
+    // TRANSFER SOL (Curve PDA -> Platform Fee Wallet)
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.platform_fee_wallet.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    msg!(" Platform claimed {} lamports in fees from {}", amount, ctx.accounts.mint.key());
+
+    emit_cpi!(platform_fees_claimed);
+
+    Ok(())
+}
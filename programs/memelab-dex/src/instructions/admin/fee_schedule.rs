@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{FeeSchedule, FeeTier, PlatformConfig, TraderVolume, MAX_FEE_TIERS};
+use crate::errors::ErrorCode;
+use crate::events::FeeScheduleUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFeeSchedule<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.platform_authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [b"fee_schedule"],
+        bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replaces the volume tiers. An empty list turns tiering off and every
+/// trader pays the flat platform fee.
+pub fn set_fee_schedule(ctx: Context<SetFeeSchedule>, tiers: Vec<FeeTier>) -> Result<()> {
+    // VALIDATION
+    require!(tiers.len() <= MAX_FEE_TIERS, ErrorCode::InvalidFeeSchedule);
+    // Higher tiers need more volume and never charge more
+    require!(
+        tiers.windows(2).all(|pair| {
+            pair[0].min_volume < pair[1].min_volume && pair[0].fee_bps >= pair[1].fee_bps
+        }),
+        ErrorCode::InvalidFeeSchedule
+    );
+    require!(
+        tiers.iter().all(|tier| tier.fee_bps <= 1000),
+        ErrorCode::InvalidFeeSchedule
+    );
+
+    let fee_schedule = &mut ctx.accounts.fee_schedule;
+    fee_schedule.tiers = tiers;
+    fee_schedule.bump = ctx.bumps.fee_schedule;
+
+    msg!(" Fee schedule updated: {} tiers", fee_schedule.tiers.len());
+
+    emit_cpi!(FeeScheduleUpdated {
+        authority: ctx.accounts.authority.key(),
+        tiers: fee_schedule.tiers.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Platform fee bps for the trader's volume tier. Without a schedule the
+/// flat platform fee applies, and without a volume account the trader is
+/// treated as having no volume; tiers only ever discount, so leaving either
+/// out never makes a trade cheaper.
+pub(crate) fn trader_platform_fee_bps(
+    fee_schedule: Option<&Account<FeeSchedule>>,
+    base_bps: u16,
+    trader_volume: Option<&Account<TraderVolume>>,
+) -> u64 {
+    let volume = trader_volume.map_or(0, |trader_volume| trader_volume.total_volume);
+
+    fee_schedule
+        .map_or(base_bps, |schedule| schedule.fee_bps_for(volume, base_bps))
+        as u64
+}
+
+/// Adds a trade to the trader's lifetime volume, if the trader passed their
+/// volume account. The new volume only counts toward the tier from the next
+/// trade on.
+pub(crate) fn record_trader_volume(
+    trader_volume: Option<&mut Account<TraderVolume>>,
+    trader: Pubkey,
+    bump: Option<u8>,
+    sol_volume: u64,
+) -> Result<()> {
+    let (Some(trader_volume), Some(bump)) = (trader_volume, bump) else {
+        return Ok(());
+    };
+
+    trader_volume.trader = trader;
+    trader_volume.bump = bump;
+    trader_volume.total_volume = trader_volume.total_volume
+        .checked_add(sol_volume)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::state::PlatformConfig;
 use crate::errors::ErrorCode; 
+use crate::events::PlatformInitialized;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitializePlatform<'info> {
     #[account(
@@ -19,11 +21,17 @@ pub struct InitializePlatform<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_platform(
     ctx: Context<InitializePlatform>,
     platform_fee_wallet: Pubkey,
     platform_fee_bps: u16,
+    max_creator_fee_bps: u16,
+    max_sniper_fee_bps: u16,
+    max_sniper_fee_duration_seconds: i64,
     platform_token_creation_fee: u64,
+    completion_sol_threshold: u64,
+    completion_token_threshold: u64,
 ) -> Result<()> {
     let platform_config = &mut ctx.accounts.platform_config;
 
@@ -32,13 +40,49 @@ pub fn initialize_platform(
         ErrorCode::FeeTooHigh
     );
 
+    require!(
+        max_creator_fee_bps <= 1000,
+        ErrorCode::CreatorFeeTooHigh
+    );
+
+    require!(
+        max_sniper_fee_bps <= 5000,
+        ErrorCode::SniperFeeTooHigh
+    );
+
+    require!(
+        max_sniper_fee_duration_seconds >= 0,
+        ErrorCode::InvalidSniperFeeDuration
+    );
+
     platform_config.platform_authority = ctx.accounts.authority.key();
+    platform_config.pending_authority = Pubkey::default();
     platform_config.platform_fee_wallet = platform_fee_wallet;
     platform_config.platform_fee_bps = platform_fee_bps;
+    platform_config.max_creator_fee_bps = max_creator_fee_bps;
+    platform_config.referral_fee_bps = 0;
+    platform_config.max_sniper_fee_bps = max_sniper_fee_bps;
+    platform_config.max_sniper_fee_duration_seconds = max_sniper_fee_duration_seconds;
     platform_config.platform_token_creation_fee = platform_token_creation_fee;
+    platform_config.completion_sol_threshold = completion_sol_threshold;
+    platform_config.completion_token_threshold = completion_token_threshold;
     platform_config.total_tokens_created = 0;
+    platform_config.paused = false;
     
     platform_config.bump = ctx.bumps.platform_config;
 
+    emit_cpi!(PlatformInitialized {
+        authority: platform_config.platform_authority,
+        platform_fee_wallet,
+        platform_fee_bps,
+        max_creator_fee_bps,
+        max_sniper_fee_bps,
+        max_sniper_fee_duration_seconds,
+        platform_token_creation_fee,
+        completion_sol_threshold,
+        completion_token_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
\ No newline at end of file
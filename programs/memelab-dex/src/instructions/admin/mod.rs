@@ -1,3 +1,13 @@
 pub mod initialize;
+pub mod update_config;
+pub mod transfer_authority;
+pub mod pause;
+pub mod claim_platform_fees;
+pub mod fee_schedule;
 
-pub use initialize::*;
\ No newline at end of file
+pub use initialize::*;
+pub use update_config::*;
+pub use transfer_authority::*;
+pub use pause::*;
+pub use claim_platform_fees::*;
+pub use fee_schedule::*;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{PlatformConfig, TokenBondingCurve};
+use crate::errors::ErrorCode;
+use crate::events::PauseUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPlatformPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.platform_authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTokenPaused<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.platform_authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_platform_paused(ctx: Context<SetPlatformPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.platform_config.paused = paused;
+
+    msg!(" Platform {}", if paused { "PAUSED" } else { "unpaused" });
+
+    emit_cpi!(PauseUpdated {
+        authority: ctx.accounts.authority.key(),
+        mint: Pubkey::default(),
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn set_token_paused(ctx: Context<SetTokenPaused>, paused: bool) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+    bonding_curve.paused = paused as u8;
+    drop(bonding_curve);
+
+    msg!(" Token {} {}", ctx.accounts.mint.key(), if paused { "PAUSED" } else { "unpaused" });
+
+    emit_cpi!(PauseUpdated {
+        authority: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key(),
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
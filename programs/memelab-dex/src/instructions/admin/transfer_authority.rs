@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::errors::ErrorCode;
+use crate::events::{AuthorityTransferAccepted, AuthorityTransferCancelled, AuthorityTransferProposed};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.platform_authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    // The proposed authority must sign, so a mistyped key can never take over
+    pub new_authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.platform_authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn propose_authority(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    require_keys_neq!(new_authority, Pubkey::default(), ErrorCode::InvalidAuthority);
+
+    let platform_config = &mut ctx.accounts.platform_config;
+    platform_config.pending_authority = new_authority;
+
+    msg!(" Authority transfer proposed: {} -> {}",
+        platform_config.platform_authority, new_authority);
+
+    emit_cpi!(AuthorityTransferProposed {
+        platform_authority: platform_config.platform_authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    require_keys_neq!(
+        platform_config.pending_authority,
+        Pubkey::default(),
+        ErrorCode::NoPendingAuthority
+    );
+    require_keys_eq!(
+        platform_config.pending_authority,
+        ctx.accounts.new_authority.key(),
+        ErrorCode::Unauthorized
+    );
+
+    let old_authority = platform_config.platform_authority;
+    platform_config.platform_authority = platform_config.pending_authority;
+    platform_config.pending_authority = Pubkey::default();
+
+    msg!(" Authority transferred: {} -> {}",
+        old_authority, platform_config.platform_authority);
+
+    emit_cpi!(AuthorityTransferAccepted {
+        old_authority,
+        platform_authority: platform_config.platform_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    require_keys_neq!(
+        platform_config.pending_authority,
+        Pubkey::default(),
+        ErrorCode::NoPendingAuthority
+    );
+
+    let cancelled_authority = platform_config.pending_authority;
+    platform_config.pending_authority = Pubkey::default();
+
+    msg!(" Authority transfer to {} cancelled", cancelled_authority);
+
+    emit_cpi!(AuthorityTransferCancelled {
+        platform_authority: platform_config.platform_authority,
+        cancelled_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
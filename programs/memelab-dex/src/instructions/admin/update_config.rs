@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::errors::ErrorCode;
+use crate::events::PlatformConfigUpdated;
+
+/// Every field is optional; `None` leaves the current value unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdatePlatformConfigParams {
+    pub platform_fee_wallet: Option<Pubkey>,
+    pub platform_fee_bps: Option<u16>,
+    pub max_creator_fee_bps: Option<u16>,
+    pub referral_fee_bps: Option<u16>,
+    pub max_sniper_fee_bps: Option<u16>,
+    pub max_sniper_fee_duration_seconds: Option<i64>,
+    pub platform_token_creation_fee: Option<u64>,
+    pub completion_sol_threshold: Option<u64>,
+    pub completion_token_threshold: Option<u64>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = platform_config.platform_authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn update_platform_config(
+    ctx: Context<UpdatePlatformConfig>,
+    params: UpdatePlatformConfigParams,
+) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+
+    // Snapshot old values for the event
+    let old_platform_fee_wallet = platform_config.platform_fee_wallet;
+    let old_platform_fee_bps = platform_config.platform_fee_bps;
+    let old_max_creator_fee_bps = platform_config.max_creator_fee_bps;
+    let old_referral_fee_bps = platform_config.referral_fee_bps;
+    let old_max_sniper_fee_bps = platform_config.max_sniper_fee_bps;
+    let old_max_sniper_fee_duration_seconds = platform_config.max_sniper_fee_duration_seconds;
+    let old_platform_token_creation_fee = platform_config.platform_token_creation_fee;
+    let old_completion_sol_threshold = platform_config.completion_sol_threshold;
+    let old_completion_token_threshold = platform_config.completion_token_threshold;
+
+    // VALIDATION
+    if let Some(platform_fee_wallet) = params.platform_fee_wallet {
+        require_keys_neq!(
+            platform_fee_wallet,
+            Pubkey::default(),
+            ErrorCode::InvalidFeeWallet
+        );
+        platform_config.platform_fee_wallet = platform_fee_wallet;
+    }
+
+    if let Some(platform_fee_bps) = params.platform_fee_bps {
+        require!(
+            platform_fee_bps <= 1000,
+            ErrorCode::FeeTooHigh
+        );
+        platform_config.platform_fee_bps = platform_fee_bps;
+    }
+
+    // Only bounds tokens created after the update
+    if let Some(max_creator_fee_bps) = params.max_creator_fee_bps {
+        require!(
+            max_creator_fee_bps <= 1000,
+            ErrorCode::CreatorFeeTooHigh
+        );
+        platform_config.max_creator_fee_bps = max_creator_fee_bps;
+    }
+
+    // Share of the platform fee, so 10000 hands the whole platform fee to referrers
+    if let Some(referral_fee_bps) = params.referral_fee_bps {
+        require!(
+            referral_fee_bps <= 10000,
+            ErrorCode::ReferralFeeTooHigh
+        );
+        platform_config.referral_fee_bps = referral_fee_bps;
+    }
+
+    // Sniper bounds only apply to tokens created after the update. Capped so
+    // platform + creator + sniper fees always stay below 100%.
+    if let Some(max_sniper_fee_bps) = params.max_sniper_fee_bps {
+        require!(
+            max_sniper_fee_bps <= 5000,
+            ErrorCode::SniperFeeTooHigh
+        );
+        platform_config.max_sniper_fee_bps = max_sniper_fee_bps;
+    }
+
+    if let Some(max_sniper_fee_duration_seconds) = params.max_sniper_fee_duration_seconds {
+        require!(
+            max_sniper_fee_duration_seconds >= 0,
+            ErrorCode::InvalidSniperFeeDuration
+        );
+        platform_config.max_sniper_fee_duration_seconds = max_sniper_fee_duration_seconds;
+    }
+
+    if let Some(platform_token_creation_fee) = params.platform_token_creation_fee {
+        platform_config.platform_token_creation_fee = platform_token_creation_fee;
+    }
+
+    // Thresholds only apply to curves created after the update
+    if let Some(completion_sol_threshold) = params.completion_sol_threshold {
+        platform_config.completion_sol_threshold = completion_sol_threshold;
+    }
+
+    if let Some(completion_token_threshold) = params.completion_token_threshold {
+        platform_config.completion_token_threshold = completion_token_threshold;
+    }
+
+    emit_cpi!(PlatformConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        platform_fee_wallet: platform_config.platform_fee_wallet,
+        platform_fee_bps: platform_config.platform_fee_bps,
+        max_creator_fee_bps: platform_config.max_creator_fee_bps,
+        referral_fee_bps: platform_config.referral_fee_bps,
+        max_sniper_fee_bps: platform_config.max_sniper_fee_bps,
+        max_sniper_fee_duration_seconds: platform_config.max_sniper_fee_duration_seconds,
+        platform_token_creation_fee: platform_config.platform_token_creation_fee,
+        completion_sol_threshold: platform_config.completion_sol_threshold,
+        completion_token_threshold: platform_config.completion_token_threshold,
+        old_platform_fee_wallet,
+        old_platform_fee_bps,
+        old_max_creator_fee_bps,
+        old_referral_fee_bps,
+        old_max_sniper_fee_bps,
+        old_max_sniper_fee_duration_seconds,
+        old_platform_token_creation_fee,
+        old_completion_sol_threshold,
+        old_completion_token_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(" Platform config updated by {}", ctx.accounts.authority.key());
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::TokenBondingCurve;
+use crate::errors::ErrorCode;
+use crate::events::CreatorFeesClaimed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+/// Not gated on pause, same as claim_platform_fees: pausing stops trading,
+/// not withdrawal of fees already earned.
+pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+
+    require_keys_eq!(
+        ctx.accounts.creator.key(),
+        bonding_curve.creator,
+        ErrorCode::InvalidCreatorWallet
+    );
+
+    let amount = bonding_curve.accrued_creator_fees;
+    require!(amount > 0, ErrorCode::NoFeesToClaim);
+
+    bonding_curve.accrued_creator_fees = 0;
+
+    let creator_fees_claimed = CreatorFeesClaimed {
+        mint: bonding_curve.token_mint,
+        creator: bonding_curve.creator,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    // Drop before moving lamports
+    drop(bonding_curve);
+
+    // TRANSFER SOL (Curve PDA -> Creator)
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    msg!(" Creator claimed {} lamports in fees", amount);
+
+    emit_cpi!(creator_fees_claimed);
+
+    Ok(())
+}
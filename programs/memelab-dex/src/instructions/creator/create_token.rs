@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{system_instruction, program::invoke};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{mint_to, MintTo},
@@ -11,14 +12,50 @@ use anchor_spl::{
     },
 };
 
-use crate::state::{PlatformConfig, TokenBondingCurve};
+use crate::state::{PlatformConfig, SniperFeeDestination, TokenBondingCurve};
 use crate::errors::ErrorCode;
+use crate::events::TokenCreated;
 
 // Constants
 const INITIAL_VIRTUAL_SOL_RESERVES: u64 = 30_000_000_000; // 30 SOL
 const INITIAL_VIRTUAL_TOKEN_RESERVES: u64 = 1_000_000_000_000_000; // 1B tokens (with 6 decimals)
 const TOTAL_SUPPLY: u64 = 1_000_000_000_000_000; // 1B tokens
+const MAX_ALPHA_WITHDRAW_PENALTY_BPS: u16 = 5000; // 50%
 
+/// Per-token Alpha Vault settings chosen by the creator.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AlphaVaultParams {
+    /// When deposits open; alpha_duration_seconds counts from here (0 = immediately)
+    pub start_time: i64,
+    /// Penalty on withdraw_alpha before the phase ends (0 = free exit)
+    pub withdraw_penalty_bps: u16,
+    /// Minimum total raise; below it the launch fails and depositors are refunded (0 = none)
+    pub min_raise: u64,
+    /// Maximum SOL converted at finalize; deposits above it are refunded pro-rata (0 = none)
+    pub hard_cap: u64,
+    /// Per-wallet minimum cumulative deposit (0 = none)
+    pub min_deposit: u64,
+    /// Per-wallet maximum cumulative deposit (0 = none)
+    pub max_deposit: u64,
+    /// Allowlist merkle root (see crate::merkle); all zero = open to everyone
+    pub merkle_root: [u8; 32],
+    /// Seconds after trading goes live before any alpha tokens unlock
+    pub vesting_cliff_seconds: i64,
+    /// Seconds over which alpha tokens unlock linearly (0 = all at once)
+    pub vesting_duration_seconds: i64,
+}
+
+/// Per-token anti-sniper fee, bounded by PlatformConfig.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SniperFeeParams {
+    /// Extra buy fee when trading goes live, decaying linearly to 0 (0 = off)
+    pub fee_bps: u16,
+    /// Seconds over which the extra fee decays
+    pub duration_seconds: i64,
+    pub destination: SniperFeeDestination,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateToken<'info> {
     #[account(mut)]
@@ -31,6 +68,13 @@ pub struct CreateToken<'info> {
     )]
     pub platform_config: Box<Account<'info, PlatformConfig>>,
 
+    /// CHECK: Platform fee wallet - validated by address constraint
+    #[account(
+        mut,
+        address = platform_config.platform_fee_wallet @ ErrorCode::InvalidFeeWallet
+    )]
+    pub platform_fee_wallet: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = creator,
@@ -68,22 +112,85 @@ pub struct CreateToken<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_token(
     ctx: Context<CreateToken>,
     name: String,
     symbol: String,
     uri: String,
+    creator_fee_bps: u16,
     alpha_duration_seconds: i64,
+    alpha_vault: AlphaVaultParams,
+    sniper_fee: SniperFeeParams,
 ) -> Result<()> {
     // VALIDATION
     require!(name.len() <= 32, ErrorCode::NameTooLong);
     require!(symbol.len() <= 10, ErrorCode::SymbolTooLong);
     require!(uri.len() <= 200, ErrorCode::UriTooLong);
     require!(alpha_duration_seconds > 0, ErrorCode::InvalidAmount);
+    require!(
+        creator_fee_bps <= ctx.accounts.platform_config.max_creator_fee_bps,
+        ErrorCode::CreatorFeeTooHigh
+    );
+    require!(
+        sniper_fee.fee_bps <= ctx.accounts.platform_config.max_sniper_fee_bps,
+        ErrorCode::SniperFeeTooHigh
+    );
+    require!(
+        sniper_fee.duration_seconds >= 0
+            && sniper_fee.duration_seconds <= ctx.accounts.platform_config.max_sniper_fee_duration_seconds,
+        ErrorCode::InvalidSniperFeeDuration
+    );
+    require!(
+        alpha_vault.withdraw_penalty_bps <= MAX_ALPHA_WITHDRAW_PENALTY_BPS,
+        ErrorCode::WithdrawPenaltyTooHigh
+    );
+    require!(
+        alpha_vault.hard_cap == 0 || alpha_vault.hard_cap >= alpha_vault.min_raise,
+        ErrorCode::InvalidAlphaHardCap
+    );
+    require!(
+        alpha_vault.max_deposit == 0 || alpha_vault.max_deposit >= alpha_vault.min_deposit,
+        ErrorCode::InvalidDepositLimits
+    );
+    require!(
+        alpha_vault.vesting_cliff_seconds >= 0 && alpha_vault.vesting_duration_seconds >= 0,
+        ErrorCode::InvalidVestingSchedule
+    );
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let alpha_start_time = match alpha_vault.start_time {
+        0 => current_time,
+        start_time => {
+            require!(start_time >= current_time, ErrorCode::InvalidAlphaStartTime);
+            start_time
+        }
+    };
+    let alpha_end_time = alpha_start_time
+        .checked_add(alpha_duration_seconds)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // CHARGE TOKEN CREATION FEE
+    let creation_fee = ctx.accounts.platform_config.platform_token_creation_fee;
+
+    if creation_fee > 0 {
+        invoke(
+            &system_instruction::transfer(
+                &ctx.accounts.creator.key(),
+                &ctx.accounts.platform_fee_wallet.key(),
+                creation_fee
+            ),
+            &[
+                ctx.accounts.creator.to_account_info(),
+                ctx.accounts.platform_fee_wallet.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
 
     // INITIALIZE BONDING CURVE
     let mut bonding_curve = ctx.accounts.bonding_curve.load_init()?;
-    let current_time = Clock::get()?.unix_timestamp;
     
     // Store bump for later use
     let bonding_curve_bump = ctx.bumps.bonding_curve;
@@ -91,7 +198,12 @@ pub fn create_token(
     // Initialize State
     bonding_curve.token_mint = ctx.accounts.mint.key();
     bonding_curve.creator = ctx.accounts.creator.key();
-    bonding_curve.creator_fee_bps = 100; // 1% creator fee
+    bonding_curve.creator_fee_bps = creator_fee_bps;
+    
+    // Anti-sniper fee (decays from trading_live_at)
+    bonding_curve.sniper_fee_bps = sniper_fee.fee_bps;
+    bonding_curve.sniper_fee_duration_seconds = sniper_fee.duration_seconds;
+    bonding_curve.sniper_fee_destination = sniper_fee.destination as u8;
     
     // Virtual reserves (for bonding curve math)
     bonding_curve.virtual_sol_reserves = INITIAL_VIRTUAL_SOL_RESERVES; 
@@ -101,26 +213,49 @@ pub fn create_token(
     bonding_curve.real_sol_reserves = 0;
     bonding_curve.real_token_reserves = TOTAL_SUPPLY;
     
+    // Fee accrual
+    bonding_curve.accrued_platform_fees = 0;
+    bonding_curve.accrued_creator_fees = 0;
+    
     // Trading state
     bonding_curve.trading_live = 0;  // false (u8 for zero_copy)
+    bonding_curve.trading_live_at = 0;
     
     // Alpha vault settings
-    bonding_curve.alpha_phase_end_time = current_time
-        .checked_add(alpha_duration_seconds)
-        .ok_or(ErrorCode::Overflow)?;
+    bonding_curve.alpha_phase_start_time = alpha_start_time;
+    bonding_curve.alpha_phase_end_time = alpha_end_time;
     bonding_curve.total_alpha_sol = 0;
     bonding_curve.total_alpha_token = 0;
+    bonding_curve.alpha_withdraw_penalty_bps = alpha_vault.withdraw_penalty_bps;
+    bonding_curve.alpha_min_raise = alpha_vault.min_raise;
+    bonding_curve.alpha_hard_cap = alpha_vault.hard_cap;
+    bonding_curve.alpha_sol_used = 0;
+    bonding_curve.alpha_clearing_price = 0;
+    bonding_curve.alpha_min_deposit = alpha_vault.min_deposit;
+    bonding_curve.alpha_max_deposit = alpha_vault.max_deposit;
+    bonding_curve.alpha_merkle_root = alpha_vault.merkle_root;
+    bonding_curve.alpha_vesting_cliff_seconds = alpha_vault.vesting_cliff_seconds;
+    bonding_curve.alpha_vesting_duration_seconds = alpha_vault.vesting_duration_seconds;
+    bonding_curve.alpha_failed = 0;  // false (u8 for zero_copy)
     
     // Supply tracking
     bonding_curve.real_token_supply = TOTAL_SUPPLY;
     
     // Completion state
     bonding_curve.is_complete = 0;  // false (u8 for zero_copy)
+    bonding_curve.completion_sol_threshold = ctx.accounts.platform_config.completion_sol_threshold;
+    bonding_curve.completion_token_threshold = ctx.accounts.platform_config.completion_token_threshold;
     bonding_curve.created_at = current_time;
     
     // PDA bump
     bonding_curve.bump = bonding_curve_bump;
     
+    // Migration state
+    bonding_curve.is_migrated = 0;  // false (u8 for zero_copy)
+    
+    // Pause state
+    bonding_curve.paused = 0;  // false (u8 for zero_copy)
+    
     // Padding for alignment
     bonding_curve._padding = [0; 3];
 
@@ -193,9 +328,48 @@ pub fn create_token(
     msg!("  Mint: {}", ctx.accounts.mint.key());
     msg!("  Bonding Curve: {}", ctx.accounts.bonding_curve.key());
     msg!("  Total Supply: {}", TOTAL_SUPPLY);
+    msg!("  Creation Fee Paid: {} lamports", creation_fee);
+    msg!("  Creator Fee: {} bps", creator_fee_bps);
     msg!("  Alpha Duration: {} seconds", alpha_duration_seconds);
-    msg!("  Alpha Ends At: {}", current_time + alpha_duration_seconds);
+    msg!("  Alpha Starts At: {}", alpha_start_time);
+    msg!("  Alpha Ends At: {}", alpha_end_time);
     msg!("  Platform Total Tokens: {}", platform_config.total_tokens_created);
 
+    let bonding_curve = ctx.accounts.bonding_curve.load()?;
+    let token_created = TokenCreated {
+        mint: bonding_curve.token_mint,
+        bonding_curve: ctx.accounts.bonding_curve.key(),
+        creator: bonding_curve.creator,
+        name,
+        symbol,
+        uri,
+        creation_fee,
+        total_supply: TOTAL_SUPPLY,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        alpha_phase_start_time: bonding_curve.alpha_phase_start_time,
+        alpha_phase_end_time: bonding_curve.alpha_phase_end_time,
+        alpha_withdraw_penalty_bps: bonding_curve.alpha_withdraw_penalty_bps,
+        alpha_min_raise: bonding_curve.alpha_min_raise,
+        alpha_hard_cap: bonding_curve.alpha_hard_cap,
+        alpha_min_deposit: bonding_curve.alpha_min_deposit,
+        alpha_max_deposit: bonding_curve.alpha_max_deposit,
+        alpha_merkle_root: bonding_curve.alpha_merkle_root,
+        alpha_vesting_cliff_seconds: bonding_curve.alpha_vesting_cliff_seconds,
+        alpha_vesting_duration_seconds: bonding_curve.alpha_vesting_duration_seconds,
+        creator_fee_bps: bonding_curve.creator_fee_bps,
+        sniper_fee_bps: bonding_curve.sniper_fee_bps,
+        sniper_fee_duration_seconds: bonding_curve.sniper_fee_duration_seconds,
+        sniper_fee_destination: bonding_curve.sniper_fee_destination,
+        completion_sol_threshold: bonding_curve.completion_sol_threshold,
+        completion_token_threshold: bonding_curve.completion_token_threshold,
+        timestamp: current_time,
+    };
+    drop(bonding_curve);
+
+    emit_cpi!(token_created);
+
     Ok(())
 }
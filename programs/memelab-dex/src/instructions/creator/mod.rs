@@ -1,3 +1,7 @@
 pub mod create_token;
+pub mod set_merkle_root;
+pub mod claim_creator_fees;
 
-pub use create_token::*;
\ No newline at end of file
+pub use create_token::*;
+pub use set_merkle_root::*;
+pub use claim_creator_fees::*;
\ No newline at end of file
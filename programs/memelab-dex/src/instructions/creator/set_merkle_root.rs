@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::TokenBondingCurve;
+use crate::errors::ErrorCode;
+use crate::events::AlphaAllowlistUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAlphaMerkleRoot<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn set_alpha_merkle_root(
+    ctx: Context<SetAlphaMerkleRoot>,
+    merkle_root: [u8; 32],
+) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+
+    require_keys_eq!(
+        ctx.accounts.creator.key(),
+        bonding_curve.creator,
+        ErrorCode::Unauthorized
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Changing the allowlist once deposits are open would be unfair to depositors
+    require!(
+        current_time < bonding_curve.alpha_phase_start_time,
+        ErrorCode::AllowlistLocked
+    );
+
+    let old_merkle_root = bonding_curve.alpha_merkle_root;
+    bonding_curve.alpha_merkle_root = merkle_root;
+
+    let allowlist_updated = AlphaAllowlistUpdated {
+        mint: bonding_curve.token_mint,
+        creator: bonding_curve.creator,
+        merkle_root,
+        old_merkle_root,
+        timestamp: current_time,
+    };
+    drop(bonding_curve);
+
+    msg!(" Alpha allowlist updated for {}", ctx.accounts.mint.key());
+
+    emit_cpi!(allowlist_updated);
+
+    Ok(())
+}
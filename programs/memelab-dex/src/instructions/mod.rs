@@ -1,7 +1,9 @@
 pub mod admin;
 pub mod creator;
+pub mod pool;
 pub mod user;
 
 pub use admin::*;
 pub use creator::*;
+pub use pool::*;
 pub use user::*;
\ No newline at end of file
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{system_instruction, program::invoke};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, transfer, MintTo, Transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::LiquidityPool;
+use crate::errors::ErrorCode;
+use crate::events::LiquidityAdded;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = lp_mint,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_liquidity(
+    ctx: Context<AddLiquidity>,
+    sol_amount: u64,
+    max_token_amount: u64,
+    min_lp_out: u64,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    require!(sol_amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        pool.sol_reserves > 0 && pool.token_reserves > 0,
+        ErrorCode::InsufficientLiquidity
+    );
+
+    let total_lp = ctx.accounts.lp_mint.supply
+        .checked_add(pool.locked_lp_supply)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Deposit at the current pool ratio, rounding tokens up in the pool's favor
+    let token_amount = (sol_amount as u128)
+        .checked_mul(pool.token_reserves as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(pool.sol_reserves as u128 - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(pool.sol_reserves as u128)
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+
+    let lp_out = (sol_amount as u128)
+        .checked_mul(total_lp as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(pool.sol_reserves as u128)
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+
+    require!(lp_out > 0, ErrorCode::InvalidAmount);
+    require!(token_amount <= max_token_amount, ErrorCode::SlippageExceeded);
+    require!(lp_out >= min_lp_out, ErrorCode::SlippageExceeded);
+
+    // SOL (Provider -> Pool)
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.provider.key(),
+            &ctx.accounts.pool.key(),
+            sol_amount
+        ),
+        &[
+            ctx.accounts.provider.to_account_info(),
+            ctx.accounts.pool.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    // Tokens (Provider -> Pool)
+    transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.provider_token_account.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        ),
+        token_amount,
+    )?;
+
+    // LP shares (Pool -> Provider)
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"pool",
+        mint_key.as_ref(),
+        &[ctx.accounts.pool.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                to: ctx.accounts.provider_lp_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        lp_out,
+    )?;
+
+    // UPDATE STATE
+    let pool = &mut ctx.accounts.pool;
+    pool.sol_reserves = pool.sol_reserves
+        .checked_add(sol_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    pool.token_reserves = pool.token_reserves
+        .checked_add(token_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    msg!(" Added liquidity: {} SOL, {} tokens for {} LP",
+        sol_amount, token_amount, lp_out);
+
+    emit_cpi!(LiquidityAdded {
+        mint: mint_key,
+        provider: ctx.accounts.provider.key(),
+        sol_amount,
+        token_amount,
+        lp_amount: lp_out,
+        sol_reserves: pool.sol_reserves,
+        token_reserves: pool.token_reserves,
+        lp_supply: total_lp
+            .checked_add(lp_out)
+            .ok_or(ErrorCode::MathOverflow)?,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
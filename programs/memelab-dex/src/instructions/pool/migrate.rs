@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::{LiquidityPool, TokenBondingCurve};
+use crate::errors::ErrorCode;
+use crate::events::CurveMigrated;
+use crate::maths::integer_sqrt;
+
+// Constants
+pub const POOL_FEE_BPS: u16 = 25; // 0.25% swap fee, paid to LPs
+const LP_DECIMALS: u8 = 6;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateToPool<'info> {
+    // Permissionless: anyone can crank a completed curve and pays the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve,
+    )]
+    pub bonding_curve_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + LiquidityPool::INIT_SPACE,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"lp_mint", mint.key().as_ref()],
+        bump,
+        mint::decimals = LP_DECIMALS,
+        mint::authority = pool,
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_to_pool(ctx: Context<MigrateToPool>) -> Result<()> {
+    let bonding_curve = ctx.accounts.bonding_curve.load()?;
+
+    // VALIDATION
+    require!(bonding_curve.is_complete == 1, ErrorCode::CurveNotComplete);
+    require!(bonding_curve.is_migrated == 0, ErrorCode::AlreadyMigrated);
+
+    // Only the curve's trading reserves move. Unclaimed alpha tokens stay
+    // in the curve vault so depositors can still claim_alpha.
+    let sol_amount = bonding_curve.real_sol_reserves;
+    let token_amount = bonding_curve.real_token_reserves;
+
+    require!(sol_amount > 0 && token_amount > 0, ErrorCode::InsufficientLiquidity);
+
+    let bonding_curve_bump = bonding_curve.bump;
+
+    // Drop before CPI
+    drop(bonding_curve);
+
+    // TRANSFER TOKENS (Curve Vault -> Pool Vault)
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"bonding_curve",
+        mint_key.as_ref(),
+        &[bonding_curve_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                to: ctx.accounts.pool_token_account.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        token_amount,
+    )?;
+
+    // TRANSFER SOL (Curve PDA -> Pool PDA)
+    // Both accounts are program owned, so move lamports directly
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= sol_amount;
+    **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? += sol_amount;
+
+    // INITIALIZE POOL
+    // Initial LP shares are recorded as locked and never minted, so the
+    // migrated liquidity can never be pulled out of the pool.
+    let initial_lp = integer_sqrt(
+        (sol_amount as u128)
+            .checked_mul(token_amount as u128)
+            .ok_or(ErrorCode::MathOverflow)?,
+    ) as u64;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.token_mint = mint_key;
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.sol_reserves = sol_amount;
+    pool.token_reserves = token_amount;
+    pool.locked_lp_supply = initial_lp;
+    pool.fee_bps = POOL_FEE_BPS;
+    pool.bump = ctx.bumps.pool;
+
+    // UPDATE CURVE
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+    bonding_curve.real_sol_reserves = 0;
+    bonding_curve.real_token_reserves = 0;
+    bonding_curve.is_migrated = 1;
+
+    let curve_migrated = CurveMigrated {
+        mint: mint_key,
+        pool: pool.key(),
+        lp_mint: pool.lp_mint,
+        sol_amount,
+        token_amount,
+        locked_lp_supply: initial_lp,
+        pool_fee_bps: pool.fee_bps,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+    drop(bonding_curve);
+
+    msg!(" Migrated to pool: {} SOL, {} tokens, {} LP locked",
+        sol_amount, token_amount, initial_lp);
+
+    emit_cpi!(curve_migrated);
+
+    Ok(())
+}
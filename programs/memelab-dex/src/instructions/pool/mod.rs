@@ -0,0 +1,9 @@
+pub mod migrate;
+pub mod swap;
+pub mod add_liquidity;
+pub mod remove_liquidity;
+
+pub use migrate::*;
+pub use swap::*;
+pub use add_liquidity::*;
+pub use remove_liquidity::*;
\ No newline at end of file
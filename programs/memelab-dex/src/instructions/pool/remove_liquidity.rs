@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, transfer, Burn, Transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::LiquidityPool;
+use crate::errors::ErrorCode;
+use crate::events::LiquidityRemoved;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump = pool.bump,
+        has_one = lp_mint,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = provider,
+    )]
+    pub provider_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn remove_liquidity(
+    ctx: Context<RemoveLiquidity>,
+    lp_amount: u64,
+    min_sol_out: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    require!(lp_amount > 0, ErrorCode::InvalidAmount);
+
+    // Locked migration shares are part of the supply, so the pool can never be drained
+    let total_lp = ctx.accounts.lp_mint.supply
+        .checked_add(pool.locked_lp_supply)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let sol_out = (lp_amount as u128)
+        .checked_mul(pool.sol_reserves as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(total_lp as u128)
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+
+    let tokens_out = (lp_amount as u128)
+        .checked_mul(pool.token_reserves as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(total_lp as u128)
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+
+    require!(sol_out >= min_sol_out, ErrorCode::SlippageExceeded);
+    require!(tokens_out >= min_tokens_out, ErrorCode::SlippageExceeded);
+
+    // Burn LP shares
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.provider_lp_account.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        ),
+        lp_amount,
+    )?;
+
+    // Tokens (Pool -> Provider)
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"pool",
+        mint_key.as_ref(),
+        &[pool.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                to: ctx.accounts.provider_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        tokens_out,
+    )?;
+
+    // SOL (Pool PDA -> Provider)
+    **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? -= sol_out;
+    **ctx.accounts.provider.to_account_info().try_borrow_mut_lamports()? += sol_out;
+
+    // UPDATE STATE
+    let pool = &mut ctx.accounts.pool;
+    pool.sol_reserves = pool.sol_reserves
+        .checked_sub(sol_out)
+        .ok_or(ErrorCode::MathUnderflow)?;
+    pool.token_reserves = pool.token_reserves
+        .checked_sub(tokens_out)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    msg!(" Removed liquidity: {} LP for {} SOL, {} tokens",
+        lp_amount, sol_out, tokens_out);
+
+    emit_cpi!(LiquidityRemoved {
+        mint: mint_key,
+        provider: ctx.accounts.provider.key(),
+        sol_amount: sol_out,
+        token_amount: tokens_out,
+        lp_amount,
+        sol_reserves: pool.sol_reserves,
+        token_reserves: pool.token_reserves,
+        lp_supply: total_lp
+            .checked_sub(lp_amount)
+            .ok_or(ErrorCode::MathUnderflow)?,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{system_instruction, program::invoke};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::LiquidityPool;
+use crate::errors::ErrorCode;
+use crate::events::PoolSwapped;
+use crate::maths::calculate_swap_out;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PoolSwap<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"pool", mint.key().as_ref()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LiquidityPool>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = pool,
+    )]
+    pub pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pool_swap(
+    ctx: Context<PoolSwap>,
+    amount_in: u64,
+    min_amount_out: u64,
+    is_buy: bool,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    require!(amount_in > 0, ErrorCode::InvalidAmount);
+    require!(
+        pool.sol_reserves > 0 && pool.token_reserves > 0,
+        ErrorCode::InsufficientLiquidity
+    );
+
+    // is_buy: SOL in, tokens out. Otherwise tokens in, SOL out.
+    let (reserve_in, reserve_out) = if is_buy {
+        (pool.sol_reserves, pool.token_reserves)
+    } else {
+        (pool.token_reserves, pool.sol_reserves)
+    };
+
+    let amount_out = calculate_swap_out(amount_in, reserve_in, reserve_out, pool.fee_bps as u64)?;
+
+    require!(amount_out > 0, ErrorCode::InvalidPriceCalculation);
+    require!(amount_out < reserve_out, ErrorCode::InsufficientLiquidity);
+    require!(amount_out >= min_amount_out, ErrorCode::SlippageExceeded);
+
+    let mint_key = ctx.accounts.mint.key();
+    let pool_bump = pool.bump;
+    let seeds = &[
+        b"pool",
+        mint_key.as_ref(),
+        &[pool_bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if is_buy {
+        // SOL (User -> Pool)
+        invoke(
+            &system_instruction::transfer(
+                &ctx.accounts.user.key(),
+                &ctx.accounts.pool.key(),
+                amount_in
+            ),
+            &[
+                ctx.accounts.user.to_account_info(),
+                ctx.accounts.pool.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+
+        // Tokens (Pool -> User)
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.pool_token_account.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount_out,
+        )?;
+    } else {
+        // Tokens (User -> Pool)
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.pool_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount_in,
+        )?;
+
+        // SOL (Pool PDA -> User)
+        **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? -= amount_out;
+        **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += amount_out;
+    }
+
+    // UPDATE STATE
+    let pool = &mut ctx.accounts.pool;
+    if is_buy {
+        pool.sol_reserves = pool.sol_reserves
+            .checked_add(amount_in)
+            .ok_or(ErrorCode::MathOverflow)?;
+        pool.token_reserves = pool.token_reserves
+            .checked_sub(amount_out)
+            .ok_or(ErrorCode::MathUnderflow)?;
+    } else {
+        pool.token_reserves = pool.token_reserves
+            .checked_add(amount_in)
+            .ok_or(ErrorCode::MathOverflow)?;
+        pool.sol_reserves = pool.sol_reserves
+            .checked_sub(amount_out)
+            .ok_or(ErrorCode::MathUnderflow)?;
+    }
+
+    msg!(" Pool swap: {} in, {} out ({})",
+        amount_in, amount_out, if is_buy { "buy" } else { "sell" });
+
+    emit_cpi!(PoolSwapped {
+        mint: mint_key,
+        user: ctx.accounts.user.key(),
+        is_buy,
+        amount_in,
+        amount_out,
+        sol_reserves: pool.sol_reserves,
+        token_reserves: pool.token_reserves,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -6,10 +6,15 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::state::{TokenBondingCurve, PlatformConfig};
+use crate::state::{TokenBondingCurve, PlatformConfig, Referrer, SniperFeeDestination, FeeSchedule, TraderVolume};
 use crate::errors::ErrorCode;
-use crate::maths::calculate_tokens_out;
+use crate::events::Trade;
+use crate::maths::{calculate_fee, calculate_gross_amount, calculate_sol_in, calculate_tokens_out};
+use crate::instructions::user::finalize::finalize_alpha_if_needed;
+use crate::instructions::user::referrer::apply_referral;
+use crate::instructions::admin::fee_schedule::{record_trader_volume, trader_platform_fee_bps};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct BuyTokens<'info> {
     #[account(mut)]
@@ -45,32 +50,47 @@ pub struct BuyTokens<'info> {
     )]
     pub buyer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Platform fee wallet - validated by address constraint
+    /// Optional: earns a share of the platform fee on this trade
     #[account(
         mut,
-        address = platform_config.platform_fee_wallet
+        seeds = [b"referrer", referrer.wallet.as_ref()],
+        bump = referrer.bump,
     )]
-    pub platform_fee_wallet: UncheckedAccount<'info>,
+    pub referrer: Option<Account<'info, Referrer>>,
 
-    /// CHECK: Creator wallet - validated in function
-    #[account(mut)]
-    pub creator_fee_wallet: UncheckedAccount<'info>,
+    /// Optional: volume-tiered platform fee; without it the flat fee applies
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+
+    /// Optional: tracks lifetime volume for the fee schedule. Created on the
+    /// first trade that passes it; traders who skip it pay no rent
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + TraderVolume::INIT_SPACE,
+        seeds = [b"trader_volume", buyer.key().as_ref()],
+        bump,
+    )]
+    pub trader_volume: Option<Account<'info, TraderVolume>>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn buy_tokens(ctx: Context<BuyTokens>, lamports_sent: u64) -> Result<()> {
+pub fn buy_tokens(
+    mut ctx: Context<BuyTokens>,
+    lamports_sent: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
     let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
     let platform_config = &ctx.accounts.platform_config;
-    
-    // Verify creator wallet
-    require_keys_eq!(
-        ctx.accounts.creator_fee_wallet.key(),
-        bonding_curve.creator,
-        ErrorCode::InvalidCreatorWallet
-    );
+
+    require!(!platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
 
     // Validate amount
     require!(lamports_sent > 0, ErrorCode::InvalidAmount);
@@ -78,76 +98,31 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, lamports_sent: u64) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
 
     // --- FINALIZE ALPHA PHASE IF NEEDED ---
-    if bonding_curve.trading_live == 0 {
-        require!(
-            current_time >= bonding_curve.alpha_phase_end_time,
-            ErrorCode::AlphaPhaseNotEnded
-        );
-        
-        // Finalize alpha phase
-        let total_raised = bonding_curve.total_alpha_sol;
-
-        if total_raised > 0 {
-            // Calculate tokens for alpha depositors
-            let tokens_for_alpha = calculate_tokens_out(
-                total_raised,
-                bonding_curve.virtual_sol_reserves,
-                bonding_curve.virtual_token_reserves,
-            )?;
-            
-            // CRITICAL FIX: Store this for claim_alpha to work
-            bonding_curve.total_alpha_token = tokens_for_alpha;
-            
-            // Update reserves to reflect alpha allocation
-            bonding_curve.virtual_sol_reserves = bonding_curve.virtual_sol_reserves
-                .checked_add(total_raised)
-                .ok_or(ErrorCode::MathOverflow)?;
-            
-            bonding_curve.virtual_token_reserves = bonding_curve.virtual_token_reserves
-                .checked_sub(tokens_for_alpha)
-                .ok_or(ErrorCode::MathUnderflow)?;
-            
-            bonding_curve.real_sol_reserves = bonding_curve.real_sol_reserves
-                .checked_add(total_raised)
-                .ok_or(ErrorCode::MathOverflow)?;
-            
-            bonding_curve.real_token_reserves = bonding_curve.real_token_reserves
-                .checked_sub(tokens_for_alpha)
-                .ok_or(ErrorCode::MathUnderflow)?;
-            
-            msg!(" Alpha Vault Finalized: {} SOL raised, {} tokens allocated", 
-                total_raised, tokens_for_alpha);
-        }
-
-        bonding_curve.trading_live = 1;
-        msg!(" Trading is now LIVE!");
-    }
+    let alpha_finalized = finalize_alpha_if_needed(&mut bonding_curve, current_time)?;
 
     // Trading must be live at this point
     require_eq!(bonding_curve.trading_live, 1, ErrorCode::TradingNotLive);
+    require!(bonding_curve.is_complete == 0, ErrorCode::SaleComplete);
 
     // --- CALCULATE FEES ---
-    let fee_bps = platform_config.platform_fee_bps as u64;
+    let fee_bps = trader_platform_fee_bps(
+        ctx.accounts.fee_schedule.as_ref(),
+        platform_config.platform_fee_bps,
+        ctx.accounts.trader_volume.as_ref(),
+    );
     let creator_bps = bonding_curve.creator_fee_bps as u64;
+    let sniper_bps = bonding_curve.current_sniper_fee_bps(current_time);
 
-    let platform_fee = lamports_sent
-        .checked_mul(fee_bps)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(10000)
-        .ok_or(ErrorCode::MathOverflow)?;
-    
-    let creator_fee = lamports_sent
-        .checked_mul(creator_bps)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(10000)
-        .ok_or(ErrorCode::MathOverflow)?;
+    let mut platform_fee = calculate_fee(lamports_sent, fee_bps)?;
+    let mut creator_fee = calculate_fee(lamports_sent, creator_bps)?;
+    let mut sniper_fee = calculate_fee(lamports_sent, sniper_bps)?;
 
-    let total_fees = platform_fee
-        .checked_add(creator_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
-    
-    let sol_for_tokens = lamports_sent
-        .checked_sub(total_fees)
+    let mut sol_for_tokens = lamports_sent
+        .checked_sub(platform_fee)
+        .ok_or(ErrorCode::MathUnderflow)?
+        .checked_sub(creator_fee)
+        .ok_or(ErrorCode::MathUnderflow)?
+        .checked_sub(sniper_fee)
         .ok_or(ErrorCode::MathUnderflow)?;
 
     // --- CALCULATE TOKENS OUT ---
@@ -157,29 +132,280 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, lamports_sent: u64) -> Result<()> {
         bonding_curve.virtual_token_reserves,
     )?;
 
+    // --- CAP AT COMPLETION ---
+    let (sol_filled, tokens_out, completes) =
+        fill_to_completion(&bonding_curve, sol_for_tokens, tokens_out)?;
+
+    if sol_filled < sol_for_tokens {
+        // Partial fill: only charge for what fits on the curve. The rest is
+        // never pulled from the buyer, which is the refund.
+        let lamports_in = calculate_gross_amount(
+            sol_filled,
+            fee_bps
+                .checked_add(creator_bps)
+                .and_then(|bps| bps.checked_add(sniper_bps))
+                .ok_or(ErrorCode::MathOverflow)?,
+        )?
+        .min(lamports_sent);
+
+        platform_fee = calculate_fee(lamports_in, fee_bps)?;
+        creator_fee = calculate_fee(lamports_in, creator_bps)?;
+        sniper_fee = calculate_fee(lamports_in, sniper_bps)?;
+        sol_for_tokens = lamports_in
+            .checked_sub(platform_fee)
+            .ok_or(ErrorCode::MathUnderflow)?
+            .checked_sub(creator_fee)
+            .ok_or(ErrorCode::MathUnderflow)?
+            .checked_sub(sniper_fee)
+            .ok_or(ErrorCode::MathUnderflow)?;
+
+        msg!(" Partial fill at completion: {} lamports refunded", lamports_sent - lamports_in);
+    }
+
+    let total_fees = platform_fee
+        .checked_add(creator_fee)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(sniper_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
     require!(tokens_out > 0, ErrorCode::InvalidPriceCalculation);
     require!(
         tokens_out <= bonding_curve.real_token_reserves,
         ErrorCode::InsufficientTokenReserves
     );
 
-    // Store bump before dropping
-    let bonding_curve_bump = bonding_curve.bump;
-    
+    // --- SLIPPAGE CHECK ---
+    // Checked against the post-fee, post-finalization reserves so a sandwich
+    // (or a large alpha allocation landing first) cannot fill at a worse price.
+    require!(tokens_out >= min_tokens_out, ErrorCode::SlippageExceeded);
+
+    if completes {
+        bonding_curve.is_complete = 1;
+    }
+
     // Drop mutable reference before transfers
     drop(bonding_curve);
 
-    // --- TRANSFER SOL (Buyer -> Bonding Curve & Fees) ---
+    if let Some(alpha_finalized) = alpha_finalized {
+        emit_cpi!(alpha_finalized);
+    }
+
+    let completes = settle_buy(&mut ctx, sol_for_tokens, platform_fee, creator_fee, sniper_fee, tokens_out)?;
+
+    msg!(" Bought {} tokens for {} SOL (fees: {} SOL)", 
+        tokens_out, sol_for_tokens, total_fees);
+
+    if completes {
+        msg!(" Bonding curve complete! Ready for migration");
+    }
+
+    Ok(())
+}
+
+pub fn buy_exact_tokens(
+    mut ctx: Context<BuyTokens>,
+    token_amount: u64,
+    max_sol_in: u64,
+) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+    let platform_config = &ctx.accounts.platform_config;
+
+    require!(!platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
+
+    // Validate amount
+    require!(token_amount > 0, ErrorCode::InvalidAmount);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // --- FINALIZE ALPHA PHASE IF NEEDED ---
+    let alpha_finalized = finalize_alpha_if_needed(&mut bonding_curve, current_time)?;
+
+    // Trading must be live at this point
+    require_eq!(bonding_curve.trading_live, 1, ErrorCode::TradingNotLive);
+    require!(bonding_curve.is_complete == 0, ErrorCode::SaleComplete);
+
+    require!(
+        token_amount <= bonding_curve.real_token_reserves,
+        ErrorCode::InsufficientTokenReserves
+    );
+
+    // --- CALCULATE SOL IN (inverse of calculate_tokens_out) ---
+    let sol_needed = calculate_sol_in(
+        token_amount,
+        bonding_curve.virtual_sol_reserves,
+        bonding_curve.virtual_token_reserves,
+    )?;
+
+    // --- GROSS UP FOR FEES ---
+    let fee_bps = trader_platform_fee_bps(
+        ctx.accounts.fee_schedule.as_ref(),
+        platform_config.platform_fee_bps,
+        ctx.accounts.trader_volume.as_ref(),
+    );
+    let creator_bps = bonding_curve.creator_fee_bps as u64;
+    let sniper_bps = bonding_curve.current_sniper_fee_bps(current_time);
+
+    let lamports_in = calculate_gross_amount(
+        sol_needed,
+        fee_bps
+            .checked_add(creator_bps)
+            .and_then(|bps| bps.checked_add(sniper_bps))
+            .ok_or(ErrorCode::MathOverflow)?,
+    )?;
+
+    let platform_fee = calculate_fee(lamports_in, fee_bps)?;
+    let creator_fee = calculate_fee(lamports_in, creator_bps)?;
+    let sniper_fee = calculate_fee(lamports_in, sniper_bps)?;
+
+    let total_fees = platform_fee
+        .checked_add(creator_fee)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(sniper_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Any rounding dust above sol_needed stays in the curve
+    let sol_for_tokens = lamports_in
+        .checked_sub(total_fees)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    // --- SLIPPAGE CHECK ---
+    require!(lamports_in <= max_sol_in, ErrorCode::SlippageExceeded);
+
+    // --- COMPLETION CHECK ---
+    // Exact-output buys are never partially filled
+    let (_, tokens_filled, completes) =
+        fill_to_completion(&bonding_curve, sol_for_tokens, token_amount)?;
+    require!(tokens_filled == token_amount, ErrorCode::MarketCapReached);
+
+    if completes {
+        bonding_curve.is_complete = 1;
+    }
+
+    // Drop mutable reference before transfers
+    drop(bonding_curve);
+
+    if let Some(alpha_finalized) = alpha_finalized {
+        emit_cpi!(alpha_finalized);
+    }
+
+    let completes = settle_buy(&mut ctx, sol_for_tokens, platform_fee, creator_fee, sniper_fee, token_amount)?;
+
+    msg!(" Bought exactly {} tokens for {} SOL (fees: {} SOL)",
+        token_amount, sol_for_tokens, total_fees);
+
+    if completes {
+        msg!(" Bonding curve complete! Ready for migration");
+    }
+
+    Ok(())
+}
+
+/// Clamps a buy so it never pushes the curve past its completion thresholds.
+/// Returns the SOL actually used, the tokens filled, and whether this fill
+/// completes the curve.
+fn fill_to_completion(
+    bonding_curve: &TokenBondingCurve,
+    sol_in: u64,
+    tokens_out: u64,
+) -> Result<(u64, u64, bool)> {
+    let sol_room = if bonding_curve.completion_sol_threshold > 0 {
+        bonding_curve.completion_sol_threshold
+            .saturating_sub(bonding_curve.real_sol_reserves)
+    } else {
+        u64::MAX
+    };
+    let token_room = bonding_curve.real_token_reserves
+        .saturating_sub(bonding_curve.completion_token_threshold);
+
+    if sol_in < sol_room && tokens_out < token_room {
+        return Ok((sol_in, tokens_out, false));
+    }
+
+    // SOL threshold is hit first
+    if sol_in >= sol_room {
+        let tokens_for_sol_room = calculate_tokens_out(
+            sol_room,
+            bonding_curve.virtual_sol_reserves,
+            bonding_curve.virtual_token_reserves,
+        )?;
+
+        if tokens_for_sol_room < token_room {
+            return Ok((sol_room, tokens_for_sol_room, true));
+        }
+    }
+
+    // Token threshold is hit first
+    let sol_for_token_room = calculate_sol_in(
+        token_room,
+        bonding_curve.virtual_sol_reserves,
+        bonding_curve.virtual_token_reserves,
+    )?
+    .min(sol_in);
+
+    Ok((sol_for_token_room, token_room, true))
+}
+
+/// Moves SOL in (reserves + accrued fees + referral share), tokens out, and
+/// updates reserves. The anti-sniper fee is routed to the curve's configured
+/// destination. Returns whether the curve is complete after the buy.
+fn settle_buy(
+    ctx: &mut Context<BuyTokens>,
+    sol_for_tokens: u64,
+    platform_fee: u64,
+    creator_fee: u64,
+    sniper_fee: u64,
+    tokens_out: u64,
+) -> Result<bool> {
+    // --- TRANSFER SOL (Buyer -> Bonding Curve) ---
     // FIX: Use system_instruction::transfer when sending FROM buyer (signer without data)
+    // Fees ride along into the curve PDA and accrue until claimed.
     
     let bonding_curve_key = ctx.accounts.bonding_curve.key();
+    let bonding_curve_bump = ctx.bumps.bonding_curve;
+
+    // Trade size before fees, i.e. everything the buyer pays. Sells count
+    // sol_out_gross, so both sides measure volume the same way.
+    let sol_volume = sol_for_tokens
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(creator_fee)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(sniper_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Referrer's cut comes out of the platform fee, never the trader
+    let referral_fee = apply_referral(
+        ctx.accounts.referrer.as_mut(),
+        ctx.accounts.buyer.key(),
+        ctx.accounts.platform_config.referral_fee_bps,
+        platform_fee,
+        sol_volume,
+    )?;
+    let platform_fee = platform_fee
+        .checked_sub(referral_fee)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    record_trader_volume(
+        ctx.accounts.trader_volume.as_mut(),
+        ctx.accounts.buyer.key(),
+        ctx.bumps.trader_volume,
+        sol_volume,
+    )?;
+
+    let lamports_in = sol_for_tokens
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(creator_fee)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(sniper_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
     
-    // Transfer SOL to bonding curve
     invoke(
         &system_instruction::transfer(
             &ctx.accounts.buyer.key(),
             &bonding_curve_key,
-            sol_for_tokens
+            lamports_in
         ),
         &[
             ctx.accounts.buyer.to_account_info(),
@@ -188,36 +414,21 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, lamports_sent: u64) -> Result<()> {
         ],
     )?;
 
-    // Transfer platform fee
-    if platform_fee > 0 {
-        invoke(
-            &system_instruction::transfer(
-                &ctx.accounts.buyer.key(),
-                &ctx.accounts.platform_fee_wallet.key(),
-                platform_fee
-            ),
-            &[
-                ctx.accounts.buyer.to_account_info(),
-                ctx.accounts.platform_fee_wallet.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-        )?;
-    }
-
-    // Transfer creator fee
-    if creator_fee > 0 {
-        invoke(
-            &system_instruction::transfer(
-                &ctx.accounts.buyer.key(),
-                &ctx.accounts.creator_fee_wallet.key(),
-                creator_fee
-            ),
-            &[
-                ctx.accounts.buyer.to_account_info(),
-                ctx.accounts.creator_fee_wallet.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-        )?;
+    if let Some(referrer) = &ctx.accounts.referrer {
+        if referral_fee > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &ctx.accounts.buyer.key(),
+                    &referrer.key(),
+                    referral_fee
+                ),
+                &[
+                    ctx.accounts.buyer.to_account_info(),
+                    referrer.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
     }
 
     // --- TRANSFER TOKENS (Bonding Curve -> Buyer) ---
@@ -261,8 +472,65 @@ pub fn buy_tokens(ctx: Context<BuyTokens>, lamports_sent: u64) -> Result<()> {
         .checked_sub(tokens_out)
         .ok_or(ErrorCode::MathUnderflow)?;
 
-    msg!(" Bought {} tokens for {} SOL (fees: {} SOL)", 
-        tokens_out, sol_for_tokens, total_fees);
+    bonding_curve.accrued_platform_fees = bonding_curve.accrued_platform_fees
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
 
-    Ok(())
+    bonding_curve.accrued_creator_fees = bonding_curve.accrued_creator_fees
+        .checked_add(creator_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Sniper fee is not shared with referrers
+    if sniper_fee > 0 {
+        match SniperFeeDestination::from_u8(bonding_curve.sniper_fee_destination) {
+            SniperFeeDestination::Creator => {
+                bonding_curve.accrued_creator_fees = bonding_curve.accrued_creator_fees
+                    .checked_add(sniper_fee)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            SniperFeeDestination::Platform => {
+                bonding_curve.accrued_platform_fees = bonding_curve.accrued_platform_fees
+                    .checked_add(sniper_fee)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            SniperFeeDestination::Curve => {
+                bonding_curve.real_sol_reserves = bonding_curve.real_sol_reserves
+                    .checked_add(sniper_fee)
+                    .ok_or(ErrorCode::MathOverflow)?;
+
+                // fill_to_completion only sized the buy itself. If the fee
+                // carries reserves over the threshold, complete here so the
+                // next buy doesn't find zero room left on a live curve.
+                if bonding_curve.completion_sol_threshold > 0
+                    && bonding_curve.real_sol_reserves >= bonding_curve.completion_sol_threshold
+                {
+                    bonding_curve.is_complete = 1;
+                }
+            }
+        }
+    }
+
+    let trade = Trade {
+        mint: bonding_curve.token_mint,
+        trader: ctx.accounts.buyer.key(),
+        is_buy: true,
+        sol_amount: sol_for_tokens,
+        token_amount: tokens_out,
+        platform_fee,
+        creator_fee,
+        referral_fee,
+        sniper_fee,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        is_complete: bonding_curve.is_complete == 1,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+    drop(bonding_curve);
+
+    let is_complete = trade.is_complete;
+    emit_cpi!(trade);
+
+    Ok(is_complete)
 }
@@ -5,9 +5,11 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::state::{TokenBondingCurve, UserAlphaDeposit};
+use crate::state::{PlatformConfig, TokenBondingCurve, UserAlphaDeposit};
 use crate::errors::ErrorCode;
+use crate::events::{AlphaClaimed, AlphaDepositClosed};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimAlpha<'info> {
     #[account(mut)]
@@ -21,6 +23,12 @@ pub struct ClaimAlpha<'info> {
     pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
 
     pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
   
     #[account(
         mut,
@@ -56,11 +64,17 @@ pub struct ClaimAlpha<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn claim_alpha(ctx: Context<ClaimAlpha>) -> Result<()> {
+/// `close_deposit` closes the UserAlphaDeposit back to the claimer once this
+/// claim leaves nothing left to vest. It is ignored while tokens remain locked.
+pub fn claim_alpha(ctx: Context<ClaimAlpha>, close_deposit: bool) -> Result<()> {
     let bonding_curve = ctx.accounts.bonding_curve.load()?;
     let user_deposit = &mut ctx.accounts.user_deposit;
 
     // VALIDATION CHECKS
+
+    // Check platform and token are not paused
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
     
     // Check trading is live (alpha phase must be finalized)
     require_eq!(bonding_curve.trading_live, 1, ErrorCode::TradingNotLive);
@@ -75,26 +89,23 @@ pub fn claim_alpha(ctx: Context<ClaimAlpha>) -> Result<()> {
     require!(bonding_curve.total_alpha_sol > 0, ErrorCode::ZeroAlphaDeposits);
     require!(bonding_curve.total_alpha_token > 0, ErrorCode::NoTokensOwed);
 
-    // CALCULATE USER'S SHARE
-    // Formula: (user_deposit / total_deposits) * total_allocated_tokens
-    // tokens_to_claim = (user_sol_amount * total_alpha_token) / total_alpha_sol
-    
-    let numerator = (user_deposit.sol_amount as u128)
-        .checked_mul(bonding_curve.total_alpha_token as u128)
-        .ok_or(ErrorCode::MathOverflow)?;
-        
-    let tokens_to_claim = numerator
-        .checked_div(bonding_curve.total_alpha_sol as u128)
-        .ok_or(ErrorCode::MathOverflow)?;
-
-    let amount_u64 = tokens_to_claim as u64;
+    let current_time = Clock::get()?.unix_timestamp;
+    let claim = compute_alpha_claim(&bonding_curve, user_deposit, current_time)?;
 
-    // Ensure they're getting some tokens
-    require!(amount_u64 > 0, ErrorCode::NoTokensOwed);
+    // Ensure they're getting something on this call. A tiny oversubscribed
+    // deposit can round to a zero allocation and still be owed its refund.
+    require!(
+        claim.allocation > 0 || claim.sol_refund > 0,
+        ErrorCode::NoTokensOwed
+    );
+    require!(
+        claim.tokens > 0 || claim.sol_refund > 0,
+        ErrorCode::TokensStillVesting
+    );
     
-    // Ensure bonding curve has enough tokens
+    // Ensure the curve vault still holds the tokens (trading reserves may have migrated)
     require!(
-        amount_u64 <= bonding_curve.real_token_reserves,
+        claim.tokens <= ctx.accounts.bonding_curve_token_account.amount,
         ErrorCode::InsufficientTokenReserves
     );
 
@@ -103,7 +114,9 @@ pub fn claim_alpha(ctx: Context<ClaimAlpha>) -> Result<()> {
     msg!("  User deposited: {} lamports", user_deposit.sol_amount);
     msg!("  Total alpha deposits: {} lamports", bonding_curve.total_alpha_sol);
     msg!("  Total alpha tokens: {}", bonding_curve.total_alpha_token);
-    msg!("  User's share: {} tokens", amount_u64);
+    msg!("  User's share: {} tokens", claim.allocation);
+    msg!("  Unlocked this claim: {} tokens", claim.tokens);
+    msg!("  Oversubscription refund: {} lamports", claim.sol_refund);
 
     // Store values before dropping reference
     let bonding_curve_bump = bonding_curve.bump;
@@ -120,25 +133,119 @@ pub fn claim_alpha(ctx: Context<ClaimAlpha>) -> Result<()> {
     ];
     let signer_seeds = &[&seeds[..]];
 
-    transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.bonding_curve_token_account.to_account_info(),
-                to: ctx.accounts.claimer_token_account.to_account_info(),
-                authority: ctx.accounts.bonding_curve.to_account_info(),
-            },
-            signer_seeds
-        ),
-        amount_u64,
-    )?;
+    if claim.tokens > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                    to: ctx.accounts.claimer_token_account.to_account_info(),
+                    authority: ctx.accounts.bonding_curve.to_account_info(),
+                },
+                signer_seeds
+            ),
+            claim.tokens,
+        )?;
+    }
+
+    // REFUND EXCESS SOL (Curve PDA -> User)
+    if claim.sol_refund > 0 {
+        **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= claim.sol_refund;
+        **ctx.accounts.claimer.to_account_info().try_borrow_mut_lamports()? += claim.sol_refund;
+    }
 
     // UPDATE STATE
-    user_deposit.claimed = true;
-    user_deposit.tokens_owed = amount_u64;
-
-    msg!(" User {} claimed {} tokens from alpha vault", 
-        ctx.accounts.claimer.key(), amount_u64);
+    user_deposit.tokens_owed = claim.allocation;
+    user_deposit.tokens_claimed = user_deposit.tokens_claimed
+        .checked_add(claim.tokens)
+        .ok_or(ErrorCode::MathOverflow)?;
+    user_deposit.sol_refunded = user_deposit.sol_refunded
+        .checked_add(claim.sol_refund)
+        .ok_or(ErrorCode::MathOverflow)?;
+    user_deposit.claimed = user_deposit.tokens_claimed == claim.allocation;
+
+    msg!(" User {} claimed {} tokens from alpha vault ({}/{} total)", 
+        ctx.accounts.claimer.key(), claim.tokens, user_deposit.tokens_claimed, claim.allocation);
+
+    emit_cpi!(AlphaClaimed {
+        mint: mint_key,
+        user: ctx.accounts.claimer.key(),
+        sol_deposited: user_deposit.sol_amount,
+        token_amount: claim.tokens,
+        total_claimed: user_deposit.tokens_claimed,
+        total_allocation: claim.allocation,
+        sol_refunded: claim.sol_refund,
+        timestamp: current_time,
+    });
+
+    // CLOSE DEPOSIT (optional, returns rent to the claimer)
+    if close_deposit && user_deposit.claimed {
+        user_deposit.close(ctx.accounts.claimer.to_account_info())?;
+
+        msg!(" Closed alpha deposit for {}", ctx.accounts.claimer.key());
+
+        emit_cpi!(AlphaDepositClosed {
+            mint: mint_key,
+            user: ctx.accounts.claimer.key(),
+            timestamp: current_time,
+        });
+    }
     
     Ok(())
 }
+
+/// What a depositor receives on one claim_alpha call.
+pub(crate) struct AlphaClaim {
+    /// Full pro-rata token allocation
+    pub allocation: u64,
+    /// Newly unlocked tokens to transfer now
+    pub tokens: u64,
+    /// Oversubscription refund, paid on the first claim only
+    pub sol_refund: u64,
+}
+
+pub(crate) fn compute_alpha_claim(
+    bonding_curve: &TokenBondingCurve,
+    user_deposit: &UserAlphaDeposit,
+    current_time: i64,
+) -> Result<AlphaClaim> {
+    let first_claim = user_deposit.tokens_owed == 0;
+
+    // CALCULATE USER'S SHARE
+    // Formula: (user_deposit / total_deposits) * total_allocated_tokens
+    // allocation = (user_sol_amount * total_alpha_token) / total_alpha_sol
+    let allocation = if first_claim {
+        (user_deposit.sol_amount as u128)
+            .checked_mul(bonding_curve.total_alpha_token as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(bonding_curve.total_alpha_sol as u128)
+            .ok_or(ErrorCode::MathOverflow)? as u64
+    } else {
+        user_deposit.tokens_owed
+    };
+
+    // VESTING
+    // Only the unlocked portion not yet transferred is claimable
+    let tokens = bonding_curve
+        .alpha_unlocked(allocation, current_time)
+        .saturating_sub(user_deposit.tokens_claimed);
+
+    // OVERSUBSCRIPTION REFUND
+    // SOL above the hard cap was never converted: return the user's share of it
+    // sol_refund = (user_sol_amount * (total_alpha_sol - alpha_sol_used)) / total_alpha_sol
+    let sol_refund = if first_claim {
+        let excess_sol = bonding_curve.total_alpha_sol
+            .checked_sub(bonding_curve.alpha_sol_used)
+            .ok_or(ErrorCode::MathUnderflow)?;
+
+        (user_deposit.sol_amount as u128)
+            .checked_mul(excess_sol as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(bonding_curve.total_alpha_sol as u128)
+            .ok_or(ErrorCode::MathOverflow)? as u64
+    } else {
+        0
+    };
+
+    Ok(AlphaClaim { allocation, tokens, sol_refund })
+}
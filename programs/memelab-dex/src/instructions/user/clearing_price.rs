@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::TokenBondingCurve;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct GetAlphaClearingPrice<'info> {
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+/// Read-only: returns the price every alpha depositor paid, in lamports per
+/// token base unit scaled by maths::PRICE_SCALE, via return data.
+pub fn get_alpha_clearing_price(ctx: Context<GetAlphaClearingPrice>) -> Result<u64> {
+    let bonding_curve = ctx.accounts.bonding_curve.load()?;
+
+    // Only known once the vault has been filled
+    require_eq!(bonding_curve.trading_live, 1, ErrorCode::TradingNotLive);
+
+    Ok(bonding_curve.alpha_clearing_price)
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{TokenBondingCurve, UserAlphaDeposit};
+use crate::errors::ErrorCode;
+use crate::events::AlphaDepositClosed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAlphaDeposit<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"alpha_deposit",
+            user.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump = user_deposit.bump,
+        constraint = user_deposit.user == user.key() @ ErrorCode::InvalidClaimer,
+        constraint = user_deposit.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_deposit: Box<Account<'info, UserAlphaDeposit>>,
+}
+
+pub fn close_alpha_deposit(ctx: Context<CloseAlphaDeposit>) -> Result<()> {
+    let bonding_curve = ctx.accounts.bonding_curve.load()?;
+    let user_deposit = &ctx.accounts.user_deposit;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Once the deposit window ends, deposit_alpha can't re-create the PDA
+    require!(
+        current_time >= bonding_curve.alpha_phase_end_time,
+        ErrorCode::AlphaPhaseNotEnded
+    );
+
+    // Only settled deposits can be closed. A fully withdrawn deposit has
+    // nothing left to claim, and nothing to refund unless a failed launch
+    // owes back its withdraw penalty.
+    let fully_withdrawn = user_deposit.sol_amount == 0
+        && (user_deposit.penalty_paid == 0 || !bonding_curve.alpha_raise_failed(current_time));
+    require!(
+        user_deposit.claimed || user_deposit.refunded || fully_withdrawn,
+        ErrorCode::DepositNotSettled
+    );
+
+    let alpha_deposit_closed = AlphaDepositClosed {
+        mint: bonding_curve.token_mint,
+        user: user_deposit.user,
+        timestamp: current_time,
+    };
+    drop(bonding_curve);
+
+    msg!(" Closed alpha deposit for {}", ctx.accounts.user.key());
+
+    emit_cpi!(alpha_deposit_closed);
+
+    Ok(())
+}
@@ -2,10 +2,20 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{system_instruction, program::invoke};
 use anchor_spl::token_interface::Mint; 
 
-use crate::state::{TokenBondingCurve, UserAlphaDeposit};
+use crate::state::{PlatformConfig, TokenBondingCurve, UserAlphaDeposit};
 use crate::errors::ErrorCode;
+use crate::events::AlphaDeposited;
+use crate::merkle::{allowlist_leaf, verify_proof};
+
+/// Proof that the depositor is on the curve's allowlist.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowlistProof {
+    /// Individual cap encoded in the leaf (0 = none)
+    pub max_amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
 
-
+#[event_cpi]
 #[derive(Accounts)]
 pub struct DepositAlpha<'info> {
     #[account(mut)]
@@ -20,6 +30,12 @@ pub struct DepositAlpha<'info> {
     #[account(mut)]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
 
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
     #[account(
         init_if_needed,
         payer = user,
@@ -38,24 +54,62 @@ pub struct DepositAlpha<'info> {
 pub fn deposit_alpha(
     ctx: Context<DepositAlpha>,
     sol_amount: u64,
-
+    allowlist_proof: Option<AllowlistProof>,
 ) -> Result<()> {
-    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+    let bonding_curve = ctx.accounts.bonding_curve.load()?;
     let user_deposit = &mut ctx.accounts.user_deposit;
     let user = &ctx.accounts.user;
 
 
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
+
     let current_time = Clock::get()?.unix_timestamp;
 
+    // These also stop a closed deposit PDA from being re-created through
+    // init_if_needed: deposits can only be closed after the phase ends.
+    require!(
+        current_time >= bonding_curve.alpha_phase_start_time,
+        ErrorCode::AlphaPhaseNotStarted
+    );
     require!(
         current_time < bonding_curve.alpha_phase_end_time, 
         ErrorCode::AlphaPhaseEnded
     );
     require!(bonding_curve.trading_live == 0, ErrorCode::TradingAlreadyLive);
+    require!(bonding_curve.alpha_failed == 0, ErrorCode::AlphaMinRaiseNotMet);
     
     
     require!(sol_amount > 0, ErrorCode::InsufficientFunds);
 
+    // Per-wallet limits apply to the cumulative deposit
+    let user_total_sol = user_deposit.sol_amount
+        .checked_add(sol_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        user_total_sol >= bonding_curve.alpha_min_deposit,
+        ErrorCode::DepositBelowMinimum
+    );
+    require!(
+        bonding_curve.alpha_max_deposit == 0 || user_total_sol <= bonding_curve.alpha_max_deposit,
+        ErrorCode::DepositAboveMaximum
+    );
+
+    // Allowlisted launches: the leaf binds the wallet to its individual cap
+    if bonding_curve.alpha_merkle_root != [0u8; 32] {
+        let allowlist_proof = allowlist_proof.ok_or(ErrorCode::NotAllowlisted)?;
+        let leaf = allowlist_leaf(&user.key(), allowlist_proof.max_amount);
+
+        require!(
+            verify_proof(&allowlist_proof.proof, bonding_curve.alpha_merkle_root, leaf),
+            ErrorCode::NotAllowlisted
+        );
+        require!(
+            allowlist_proof.max_amount == 0 || user_total_sol <= allowlist_proof.max_amount,
+            ErrorCode::DepositAboveMaximum
+        );
+    }
+
     let bonding_curve_key = ctx.accounts.bonding_curve.key();
     
     // Drop mutable borrow before CPI
@@ -83,17 +137,28 @@ pub fn deposit_alpha(
     user_deposit.user = user.key();
     user_deposit.mint = bonding_curve.token_mint;
 
-    user_deposit.sol_amount = user_deposit.sol_amount
-        .checked_add(sol_amount)
-        .ok_or(ErrorCode::MathOverflow)?;
+    user_deposit.sol_amount = user_total_sol;
 
         user_deposit.claimed = false;
+        user_deposit.refunded = false;
         user_deposit.bump = ctx.bumps.user_deposit;
 
         bonding_curve.total_alpha_sol = bonding_curve.total_alpha_sol
         .checked_add(sol_amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
+    let alpha_deposited = AlphaDeposited {
+        mint: bonding_curve.token_mint,
+        user: user_deposit.user,
+        sol_amount,
+        user_total_sol: user_deposit.sol_amount,
+        total_alpha_sol: bonding_curve.total_alpha_sol,
+        timestamp: current_time,
+    };
+    drop(bonding_curve);
+
+    emit_cpi!(alpha_deposited);
+
     Ok(())
 
 }
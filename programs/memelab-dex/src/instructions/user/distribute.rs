@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create, get_associated_token_address_with_program_id, AssociatedToken, Create},
+    token::{transfer, Transfer},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::state::{PlatformConfig, TokenBondingCurve, UserAlphaDeposit};
+use crate::errors::ErrorCode;
+use crate::events::AlphaClaimed;
+use crate::instructions::user::claim::compute_alpha_claim;
+
+// Remaining accounts come in groups of three per depositor:
+// [UserAlphaDeposit (mut), depositor wallet (mut), depositor ATA (mut)]
+const ACCOUNTS_PER_DEPOSITOR: usize = 3;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DistributeAlpha<'info> {
+    // Permissionless crank: pays rent for any ATAs that need creating
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve,
+    )]
+    pub bonding_curve_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn distribute_alpha<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeAlpha<'info>>,
+) -> Result<()> {
+    // Copy the curve so it isn't borrowed across the CPIs below
+    let bonding_curve = *ctx.accounts.bonding_curve.load()?;
+
+    // VALIDATION CHECKS (same as claim_alpha)
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
+    require_eq!(bonding_curve.trading_live, 1, ErrorCode::TradingNotLive);
+    require!(bonding_curve.total_alpha_sol > 0, ErrorCode::ZeroAlphaDeposits);
+    require!(bonding_curve.total_alpha_token > 0, ErrorCode::NoTokensOwed);
+
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % ACCOUNTS_PER_DEPOSITOR == 0,
+        ErrorCode::InvalidRemainingAccounts
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let mint_key = ctx.accounts.mint.key();
+    let token_program_key = ctx.accounts.token_program.key();
+    let seeds = &[
+        b"bonding_curve",
+        mint_key.as_ref(),
+        &[bonding_curve.bump]
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let mut distributed: u32 = 0;
+
+    for accounts in remaining_accounts.chunks(ACCOUNTS_PER_DEPOSITOR) {
+        let (deposit_info, wallet_info, token_account_info) = (&accounts[0], &accounts[1], &accounts[2]);
+
+        // Account<T> checks the owner and discriminator; deposits are only
+        // ever created at their [alpha_deposit, user, mint] PDA
+        let mut user_deposit = Account::<UserAlphaDeposit>::try_from(deposit_info)?;
+
+        require_keys_eq!(user_deposit.mint, mint_key, ErrorCode::InvalidMint);
+        require_keys_eq!(wallet_info.key(), user_deposit.user, ErrorCode::InvalidClaimer);
+        require_keys_eq!(
+            token_account_info.key(),
+            get_associated_token_address_with_program_id(&user_deposit.user, &mint_key, &token_program_key),
+            ErrorCode::InvalidClaimer
+        );
+
+        // Skip anyone who already self-claimed, so a crank can't be griefed by a race
+        if user_deposit.claimed || user_deposit.refunded || user_deposit.sol_amount == 0 {
+            continue;
+        }
+
+        let claim = compute_alpha_claim(&bonding_curve, &user_deposit, current_time)?;
+        if claim.tokens == 0 && claim.sol_refund == 0 {
+            continue;
+        }
+
+        // CREATE ATA IF MISSING
+        if token_account_info.data_is_empty() {
+            create(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                Create {
+                    payer: ctx.accounts.payer.to_account_info(),
+                    associated_token: token_account_info.clone(),
+                    authority: wallet_info.clone(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        // TRANSFER TOKENS
+        if claim.tokens > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.bonding_curve_token_account.to_account_info(),
+                        to: token_account_info.clone(),
+                        authority: ctx.accounts.bonding_curve.to_account_info(),
+                    },
+                    signer_seeds
+                ),
+                claim.tokens,
+            )?;
+        }
+
+        // REFUND EXCESS SOL (Curve PDA -> User)
+        if claim.sol_refund > 0 {
+            **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= claim.sol_refund;
+            **wallet_info.try_borrow_mut_lamports()? += claim.sol_refund;
+        }
+
+        // UPDATE STATE
+        user_deposit.tokens_owed = claim.allocation;
+        user_deposit.tokens_claimed = user_deposit.tokens_claimed
+            .checked_add(claim.tokens)
+            .ok_or(ErrorCode::MathOverflow)?;
+        user_deposit.sol_refunded = user_deposit.sol_refunded
+            .checked_add(claim.sol_refund)
+            .ok_or(ErrorCode::MathOverflow)?;
+        user_deposit.claimed = user_deposit.tokens_claimed == claim.allocation;
+        user_deposit.exit(ctx.program_id)?;
+
+        emit_cpi!(AlphaClaimed {
+            mint: mint_key,
+            user: user_deposit.user,
+            sol_deposited: user_deposit.sol_amount,
+            token_amount: claim.tokens,
+            total_claimed: user_deposit.tokens_claimed,
+            total_allocation: claim.allocation,
+            sol_refunded: claim.sol_refund,
+            timestamp: current_time,
+        });
+
+        distributed += 1;
+    }
+
+    msg!(" Distributed alpha to {} depositors", distributed);
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::TokenBondingCurve;
+use crate::errors::ErrorCode;
+use crate::events::{AlphaFinalized, AlphaRaiseFailed};
+use crate::maths::{calculate_clearing_price, calculate_tokens_out};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeAlpha<'info> {
+    // Permissionless crank: no signer required beyond the fee payer
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn finalize_alpha(ctx: Context<FinalizeAlpha>) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+
+    require!(bonding_curve.trading_live == 0, ErrorCode::TradingAlreadyLive);
+    require!(bonding_curve.alpha_failed == 0, ErrorCode::AlphaMinRaiseNotMet);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Below the minimum raise: record the failure so depositors can refund_alpha.
+    // Trading never opens for this curve.
+    if bonding_curve.alpha_raise_failed(current_time) {
+        bonding_curve.alpha_failed = 1;
+
+        let alpha_raise_failed = AlphaRaiseFailed {
+            mint: bonding_curve.token_mint,
+            total_alpha_sol: bonding_curve.total_alpha_sol,
+            alpha_min_raise: bonding_curve.alpha_min_raise,
+            timestamp: current_time,
+        };
+        drop(bonding_curve);
+
+        msg!(" Alpha Vault failed: {} raised, {} required",
+            alpha_raise_failed.total_alpha_sol, alpha_raise_failed.alpha_min_raise);
+
+        emit_cpi!(alpha_raise_failed);
+
+        return Ok(());
+    }
+
+    let alpha_finalized = finalize_alpha_if_needed(&mut bonding_curve, current_time)?;
+
+    drop(bonding_curve);
+
+    if let Some(alpha_finalized) = alpha_finalized {
+        emit_cpi!(alpha_finalized);
+    }
+
+    Ok(())
+}
+
+/// Converts the alpha vault into a curve position and opens trading,
+/// once the alpha phase is over. No-op if trading is already live.
+/// Returns the event to emit when finalization happened.
+pub(crate) fn finalize_alpha_if_needed(
+    bonding_curve: &mut TokenBondingCurve,
+    current_time: i64,
+) -> Result<Option<AlphaFinalized>> {
+    if bonding_curve.trading_live != 0 {
+        return Ok(None);
+    }
+
+    require!(
+        current_time >= bonding_curve.alpha_phase_end_time,
+        ErrorCode::AlphaPhaseNotEnded
+    );
+    require!(
+        !bonding_curve.alpha_raise_failed(current_time),
+        ErrorCode::AlphaMinRaiseNotMet
+    );
+    
+    // Finalize alpha phase
+    // Only SOL up to the hard cap goes through the curve; the rest is
+    // refunded pro-rata when depositors claim
+    let total_raised = match bonding_curve.alpha_hard_cap {
+        0 => bonding_curve.total_alpha_sol,
+        hard_cap => bonding_curve.total_alpha_sol.min(hard_cap),
+    };
+    bonding_curve.alpha_sol_used = total_raised;
+
+    if total_raised > 0 {
+        // Calculate tokens for alpha depositors.
+        // The whole vault is filled as ONE buy against the curve, so every
+        // depositor pays the same clearing price regardless of deposit order.
+        let tokens_for_alpha = calculate_tokens_out(
+            total_raised,
+            bonding_curve.virtual_sol_reserves,
+            bonding_curve.virtual_token_reserves,
+        )?;
+        
+        // CRITICAL FIX: Store this for claim_alpha to work
+        bonding_curve.total_alpha_token = tokens_for_alpha;
+        bonding_curve.alpha_clearing_price = calculate_clearing_price(total_raised, tokens_for_alpha)?;
+        
+        // Update reserves to reflect alpha allocation
+        bonding_curve.virtual_sol_reserves = bonding_curve.virtual_sol_reserves
+            .checked_add(total_raised)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        bonding_curve.virtual_token_reserves = bonding_curve.virtual_token_reserves
+            .checked_sub(tokens_for_alpha)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        
+        bonding_curve.real_sol_reserves = bonding_curve.real_sol_reserves
+            .checked_add(total_raised)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        bonding_curve.real_token_reserves = bonding_curve.real_token_reserves
+            .checked_sub(tokens_for_alpha)
+            .ok_or(ErrorCode::MathUnderflow)?;
+        
+        msg!(" Alpha Vault Finalized: {} SOL raised, {} used, {} tokens allocated", 
+            bonding_curve.total_alpha_sol, total_raised, tokens_for_alpha);
+
+        // A large enough vault can complete the curve on its own
+        let sol_threshold = bonding_curve.completion_sol_threshold;
+        if (sol_threshold > 0 && bonding_curve.real_sol_reserves >= sol_threshold)
+            || bonding_curve.real_token_reserves <= bonding_curve.completion_token_threshold
+        {
+            bonding_curve.is_complete = 1;
+            msg!(" Bonding curve complete! Ready for migration");
+        }
+    }
+
+    bonding_curve.trading_live = 1;
+    bonding_curve.trading_live_at = current_time;
+    msg!(" Trading is now LIVE!");
+
+    Ok(Some(AlphaFinalized {
+        mint: bonding_curve.token_mint,
+        total_alpha_sol: bonding_curve.total_alpha_sol,
+        alpha_sol_used: bonding_curve.alpha_sol_used,
+        total_alpha_token: bonding_curve.total_alpha_token,
+        alpha_clearing_price: bonding_curve.alpha_clearing_price,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        is_complete: bonding_curve.is_complete == 1,
+        timestamp: current_time,
+    }))
+}
@@ -1,9 +1,23 @@
 pub mod buy;
 pub mod sell;
 pub mod deposit;
+pub mod withdraw;
 pub mod claim;
+pub mod finalize;
+pub mod refund;
+pub mod close_deposit;
+pub mod distribute;
+pub mod clearing_price;
+pub mod referrer;
 
 pub use buy::*;
 pub use sell::*;
 pub use deposit::*;
-pub use claim::*;
\ No newline at end of file
+pub use withdraw::*;
+pub use claim::*;
+pub use finalize::*;
+pub use refund::*;
+pub use close_deposit::*;
+pub use distribute::*;
+pub use clearing_price::*;
+pub use referrer::*;
\ No newline at end of file
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Referrer;
+use crate::errors::ErrorCode;
+use crate::events::{ReferralFeesClaimed, ReferrerRegistered};
+use crate::maths::calculate_fee;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + Referrer::INIT_SPACE,
+        seeds = [b"referrer", wallet.key().as_ref()],
+        bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"referrer", wallet.key().as_ref()],
+        bump = referrer.bump,
+        has_one = wallet @ ErrorCode::Unauthorized,
+    )]
+    pub referrer: Account<'info, Referrer>,
+}
+
+pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.wallet = ctx.accounts.wallet.key();
+    referrer.total_volume = 0;
+    referrer.total_earned = 0;
+    referrer.accrued_fees = 0;
+    referrer.bump = ctx.bumps.referrer;
+
+    msg!(" Referrer registered: {}", referrer.wallet);
+
+    emit_cpi!(ReferrerRegistered {
+        referrer: referrer.key(),
+        wallet: referrer.wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let amount = ctx.accounts.referrer.accrued_fees;
+    require!(amount > 0, ErrorCode::NoFeesToClaim);
+
+    ctx.accounts.referrer.accrued_fees = 0;
+
+    // TRANSFER SOL (Referrer PDA -> Wallet), rent stays behind
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.wallet.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    msg!(" Referrer {} claimed {} lamports", ctx.accounts.wallet.key(), amount);
+
+    emit_cpi!(ReferralFeesClaimed {
+        referrer: ctx.accounts.referrer.key(),
+        wallet: ctx.accounts.wallet.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Carves the referrer's share out of a trade's platform fee and records
+/// the trade on the referrer. Returns the referral fee (0 without a referrer).
+/// The caller moves the lamports into the Referrer PDA.
+pub(crate) fn apply_referral(
+    referrer: Option<&mut Account<Referrer>>,
+    trader: Pubkey,
+    referral_fee_bps: u16,
+    platform_fee: u64,
+    sol_volume: u64,
+) -> Result<u64> {
+    let Some(referrer) = referrer else {
+        return Ok(0);
+    };
+
+    require_keys_neq!(referrer.wallet, trader, ErrorCode::SelfReferral);
+
+    let referral_fee = calculate_fee(platform_fee, referral_fee_bps as u64)?;
+
+    referrer.total_volume = referrer.total_volume
+        .checked_add(sol_volume)
+        .ok_or(ErrorCode::MathOverflow)?;
+    referrer.total_earned = referrer.total_earned
+        .checked_add(referral_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    referrer.accrued_fees = referrer.accrued_fees
+        .checked_add(referral_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(referral_fee)
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{PlatformConfig, TokenBondingCurve, UserAlphaDeposit};
+use crate::errors::ErrorCode;
+use crate::events::AlphaRefunded;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundAlpha<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"alpha_deposit",
+            user.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump = user_deposit.bump,
+        constraint = user_deposit.user == user.key() @ ErrorCode::InvalidClaimer,
+        constraint = user_deposit.mint == mint.key() @ ErrorCode::InvalidMint,
+        constraint = !user_deposit.refunded @ ErrorCode::AlreadyRefunded,
+    )]
+    pub user_deposit: Box<Account<'info, UserAlphaDeposit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn refund_alpha(ctx: Context<RefundAlpha>) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+    let user_deposit = &mut ctx.accounts.user_deposit;
+
+    // VALIDATION CHECKS
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Refunds only exist for launches that missed their minimum raise
+    require!(
+        bonding_curve.alpha_raise_failed(current_time),
+        ErrorCode::AlphaRaiseNotFailed
+    );
+
+    // Withdraw penalties only deepen a curve that goes on to trade, so a
+    // failed launch hands them back too
+    let penalty_refunded = user_deposit.penalty_paid;
+    let refund_amount = user_deposit.sol_amount
+        .checked_add(penalty_refunded)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(refund_amount > 0, ErrorCode::NoDepositFound);
+
+    // Record the failure if nobody has cranked finalize_alpha yet
+    bonding_curve.alpha_failed = 1;
+
+    bonding_curve.real_sol_reserves = bonding_curve.real_sol_reserves
+        .checked_sub(penalty_refunded)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    let mint_key = bonding_curve.token_mint;
+
+    // Drop before moving lamports
+    drop(bonding_curve);
+
+    // TRANSFER SOL (Curve PDA -> User)
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
+    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += refund_amount;
+
+    // UPDATE STATE
+    user_deposit.refunded = true;
+
+    msg!(" User {} refunded {} lamports from failed alpha vault",
+        ctx.accounts.user.key(), refund_amount);
+
+    emit_cpi!(AlphaRefunded {
+        mint: mint_key,
+        user: ctx.accounts.user.key(),
+        sol_amount: refund_amount,
+        penalty_refunded,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
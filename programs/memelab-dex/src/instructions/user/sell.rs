@@ -5,10 +5,14 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::state::{TokenBondingCurve, PlatformConfig};
+use crate::state::{TokenBondingCurve, PlatformConfig, Referrer, FeeSchedule, TraderVolume};
 use crate::errors::ErrorCode;
-use crate::maths::calculate_sol_out;
+use crate::events::Trade;
+use crate::maths::{calculate_fee, calculate_sol_out};
+use crate::instructions::user::referrer::apply_referral;
+use crate::instructions::admin::fee_schedule::{record_trader_volume, trader_platform_fee_bps};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SellTokens<'info> {
     #[account(mut)]
@@ -43,16 +47,31 @@ pub struct SellTokens<'info> {
     )]
     pub seller_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Platform fee wallet - validated by constraint
+    /// Optional: earns a share of the platform fee on this trade
     #[account(
         mut,
-        address = platform_config.platform_fee_wallet
+        seeds = [b"referrer", referrer.wallet.as_ref()],
+        bump = referrer.bump,
     )]
-    pub fee_wallet: UncheckedAccount<'info>,
+    pub referrer: Option<Account<'info, Referrer>>,
 
-    /// CHECK: Creator wallet - validated in function
-    #[account(mut)]
-    pub creator_wallet: UncheckedAccount<'info>,
+    /// Optional: volume-tiered platform fee; without it the flat fee applies
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Option<Account<'info, FeeSchedule>>,
+
+    /// Optional: tracks lifetime volume for the fee schedule. Created on the
+    /// first trade that passes it; traders who skip it pay no rent
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + TraderVolume::INIT_SPACE,
+        seeds = [b"trader_volume", seller.key().as_ref()],
+        bump,
+    )]
+    pub trader_volume: Option<Account<'info, TraderVolume>>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -66,16 +85,13 @@ pub fn sell_tokens(
 ) -> Result<()> {
     let bonding_curve = ctx.accounts.bonding_curve.load()?;
     let platform_config = &ctx.accounts.platform_config;
-    
-    // Verify creator wallet
-    require_keys_eq!(
-        ctx.accounts.creator_wallet.key(),
-        bonding_curve.creator,
-        ErrorCode::InvalidCreatorWallet
-    );
+
+    require!(!platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
 
     // 1. Checks
     require_eq!(bonding_curve.trading_live, 1, ErrorCode::TradingNotLive);
+    require!(bonding_curve.is_complete == 0, ErrorCode::SaleComplete);
     require!(token_amount > 0, ErrorCode::InvalidAmount);
 
     // 2. Calculate Output (Constant Product)
@@ -91,20 +107,13 @@ pub fn sell_tokens(
     );
 
     // 3. Calculate Fees
-    let fee_bps = platform_config.platform_fee_bps as u64;
-    let creator_bps = bonding_curve.creator_fee_bps as u64;
-
-    let platform_fee = sol_out_gross
-        .checked_mul(fee_bps)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(10000)
-        .ok_or(ErrorCode::MathOverflow)?;
-    
-    let creator_fee = sol_out_gross
-        .checked_mul(creator_bps)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(10000)
-        .ok_or(ErrorCode::MathOverflow)?;
+    let fee_bps = trader_platform_fee_bps(
+        ctx.accounts.fee_schedule.as_ref(),
+        platform_config.platform_fee_bps,
+        ctx.accounts.trader_volume.as_ref(),
+    );
+    let platform_fee = calculate_fee(sol_out_gross, fee_bps)?;
+    let creator_fee = calculate_fee(sol_out_gross, bonding_curve.creator_fee_bps as u64)?;
     
     let total_fees = platform_fee
         .checked_add(creator_fee)
@@ -117,12 +126,30 @@ pub fn sell_tokens(
     // 4. Slippage Check
     require!(sol_out_net >= min_sol_out, ErrorCode::SlippageExceeded);
 
-    // Store values before dropping the reference
-    let bonding_curve_bump = bonding_curve.bump;
-    
+    let referral_fee_bps = platform_config.referral_fee_bps;
+
     // Drop the read-only reference
     drop(bonding_curve);
 
+    // Referrer's cut comes out of the platform fee, never the trader
+    let referral_fee = apply_referral(
+        ctx.accounts.referrer.as_mut(),
+        ctx.accounts.seller.key(),
+        referral_fee_bps,
+        platform_fee,
+        sol_out_gross,
+    )?;
+    let platform_fee = platform_fee
+        .checked_sub(referral_fee)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    record_trader_volume(
+        ctx.accounts.trader_volume.as_mut(),
+        ctx.accounts.seller.key(),
+        ctx.bumps.trader_volume,
+        sol_out_gross,
+    )?;
+
     // 5. Transfer Tokens (User -> Vault)
     transfer(
         CpiContext::new(
@@ -136,24 +163,18 @@ pub fn sell_tokens(
         token_amount,
     )?;
 
-    // 6. Transfer SOL (Vault PDA -> User & Fees)
+    // 6. Transfer SOL (Vault PDA -> User)
     // FIX: Use direct lamport manipulation instead of system_instruction::transfer
     // This works for accounts with data (like PDAs)
-    
-    // Transfer to seller
+    // Fees stay in the curve PDA and accrue until claimed.
     **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= sol_out_net;
     **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += sol_out_net;
 
-    // Transfer platform fee
-    if platform_fee > 0 {
-        **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= platform_fee;
-        **ctx.accounts.fee_wallet.to_account_info().try_borrow_mut_lamports()? += platform_fee;
-    }
-
-    // Transfer creator fee
-    if creator_fee > 0 {
-        **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= creator_fee;
-        **ctx.accounts.creator_wallet.to_account_info().try_borrow_mut_lamports()? += creator_fee;
+    if let Some(referrer) = &ctx.accounts.referrer {
+        if referral_fee > 0 {
+            **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= referral_fee;
+            **referrer.to_account_info().try_borrow_mut_lamports()? += referral_fee;
+        }
     }
 
     // 7. Update State
@@ -175,8 +196,37 @@ pub fn sell_tokens(
         .checked_add(token_amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
+    bonding_curve.accrued_platform_fees = bonding_curve.accrued_platform_fees
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    bonding_curve.accrued_creator_fees = bonding_curve.accrued_creator_fees
+        .checked_add(creator_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
     msg!("Sold {} tokens for {} SOL net (gross: {}, fees: {})", 
         token_amount, sol_out_net, sol_out_gross, total_fees);
 
+    let trade = Trade {
+        mint: bonding_curve.token_mint,
+        trader: ctx.accounts.seller.key(),
+        is_buy: false,
+        sol_amount: sol_out_gross,
+        token_amount,
+        platform_fee,
+        creator_fee,
+        referral_fee,
+        sniper_fee: 0,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        real_sol_reserves: bonding_curve.real_sol_reserves,
+        real_token_reserves: bonding_curve.real_token_reserves,
+        is_complete: bonding_curve.is_complete == 1,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+    drop(bonding_curve);
+
+    emit_cpi!(trade);
+
     Ok(())
 }
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::{PlatformConfig, TokenBondingCurve, UserAlphaDeposit};
+use crate::errors::ErrorCode;
+use crate::events::AlphaWithdrawn;
+use crate::maths::calculate_fee;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawAlpha<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump,
+    )]
+    pub bonding_curve: AccountLoader<'info, TokenBondingCurve>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"alpha_deposit",
+            user.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump = user_deposit.bump,
+        constraint = user_deposit.user == user.key() @ ErrorCode::InvalidClaimer,
+    )]
+    pub user_deposit: Box<Account<'info, UserAlphaDeposit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_alpha(
+    ctx: Context<WithdrawAlpha>,
+    sol_amount: u64,
+) -> Result<()> {
+    let mut bonding_curve = ctx.accounts.bonding_curve.load_mut()?;
+    let user_deposit = &mut ctx.accounts.user_deposit;
+
+    require!(!ctx.accounts.platform_config.paused, ErrorCode::PlatformPaused);
+    require!(bonding_curve.paused == 0, ErrorCode::TokenPaused);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Withdrawals are only allowed while the vault is still open
+    require!(
+        current_time < bonding_curve.alpha_phase_end_time,
+        ErrorCode::AlphaPhaseEnded
+    );
+    require!(bonding_curve.trading_live == 0, ErrorCode::TradingAlreadyLive);
+
+    require!(sol_amount > 0, ErrorCode::InvalidAmount);
+    require!(
+        sol_amount <= user_deposit.sol_amount,
+        ErrorCode::WithdrawExceedsDeposit
+    );
+
+    // A partial exit can't leave the wallet below the per-wallet minimum
+    let remaining_sol = user_deposit.sol_amount - sol_amount;
+    require!(
+        remaining_sol == 0 || remaining_sol >= bonding_curve.alpha_min_deposit,
+        ErrorCode::DepositBelowMinimum
+    );
+
+    // Early-exit penalty stays in the curve. It is deliberately added to
+    // real_sol_reserves only: virtual reserves (and so the price) don't move,
+    // and if the launch succeeds it ends up in the pool at migration, in
+    // effect burned into the token's liquidity. If the launch fails there is
+    // no pool, so refund_alpha pays it back with the rest of the deposit.
+    let penalty = calculate_fee(sol_amount, bonding_curve.alpha_withdraw_penalty_bps as u64)?;
+    let payout = sol_amount
+        .checked_sub(penalty)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    // UPDATE STATE
+    user_deposit.sol_amount = remaining_sol;
+    user_deposit.penalty_paid = user_deposit.penalty_paid
+        .checked_add(penalty)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    bonding_curve.total_alpha_sol = bonding_curve.total_alpha_sol
+        .checked_sub(sol_amount)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    bonding_curve.real_sol_reserves = bonding_curve.real_sol_reserves
+        .checked_add(penalty)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let alpha_withdrawn = AlphaWithdrawn {
+        mint: bonding_curve.token_mint,
+        user: user_deposit.user,
+        sol_amount: payout,
+        penalty,
+        user_total_sol: user_deposit.sol_amount,
+        total_alpha_sol: bonding_curve.total_alpha_sol,
+        timestamp: current_time,
+    };
+
+    // Drop before moving lamports
+    drop(bonding_curve);
+
+    // TRANSFER SOL (Curve PDA -> User)
+    **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= payout;
+    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += payout;
+
+    msg!(" Withdrew {} lamports from alpha vault (penalty: {})", payout, penalty);
+
+    emit_cpi!(alpha_withdrawn);
+
+    Ok(())
+}
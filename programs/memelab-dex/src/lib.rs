@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod maths;
+pub mod merkle;
 
 
 use instructions::*; 
+use state::FeeTier;
 
 declare_id!("EdARKxJ9vBQfiapXTqfBQvtLzmwxaPSCNsS5AU5R8DCm");
 
@@ -15,57 +18,189 @@ pub mod memelab_dex {
     use super::*;
 
     // ADMIN
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_platform(
         ctx: Context<InitializePlatform>, 
         platform_fee_wallet: Pubkey,
         platform_fee_bps: u16,
+        max_creator_fee_bps: u16,
+        max_sniper_fee_bps: u16,
+        max_sniper_fee_duration_seconds: i64,
         platform_token_creation_fee: u64,
+        completion_sol_threshold: u64,
+        completion_token_threshold: u64,
     ) -> Result<()> {
         instructions::admin::initialize::initialize_platform(
             ctx,
             platform_fee_wallet,
             platform_fee_bps,
+            max_creator_fee_bps,
+            max_sniper_fee_bps,
+            max_sniper_fee_duration_seconds,
             platform_token_creation_fee,
+            completion_sol_threshold,
+            completion_token_threshold,
         )
     }
 
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        params: UpdatePlatformConfigParams,
+    ) -> Result<()> {
+        instructions::admin::update_config::update_platform_config(ctx, params)
+    }
+
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::admin::transfer_authority::propose_authority(ctx, new_authority)
+    }
+
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>
+    ) -> Result<()> {
+        instructions::admin::transfer_authority::accept_authority(ctx)
+    }
+
+    pub fn cancel_authority_transfer(
+        ctx: Context<CancelAuthorityTransfer>
+    ) -> Result<()> {
+        instructions::admin::transfer_authority::cancel_authority_transfer(ctx)
+    }
+
+    pub fn set_platform_paused(
+        ctx: Context<SetPlatformPaused>,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::admin::pause::set_platform_paused(ctx, paused)
+    }
+
+    pub fn set_token_paused(
+        ctx: Context<SetTokenPaused>,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::admin::pause::set_token_paused(ctx, paused)
+    }
+
+    pub fn claim_platform_fees(
+        ctx: Context<ClaimPlatformFees>
+    ) -> Result<()> {
+        instructions::admin::claim_platform_fees::claim_platform_fees(ctx)
+    }
+
+    pub fn set_fee_schedule(
+        ctx: Context<SetFeeSchedule>,
+        tiers: Vec<FeeTier>,
+    ) -> Result<()> {
+        instructions::admin::fee_schedule::set_fee_schedule(ctx, tiers)
+    }
+
     //  CREATOR
+    #[allow(clippy::too_many_arguments)]
     pub fn create_token(
         ctx: Context<CreateToken>,
         name: String,
         symbol: String,
         uri: String,
+        creator_fee_bps: u16,
         alpha_duration_seconds: i64,
+        alpha_vault: AlphaVaultParams,
+        sniper_fee: SniperFeeParams,
     ) -> Result<()> {
         instructions::creator::create_token::create_token(
             ctx,
             name,
             symbol,
             uri,
+            creator_fee_bps,
             alpha_duration_seconds,
+            alpha_vault,
+            sniper_fee,
         )
     }
 
+    pub fn set_alpha_merkle_root(
+        ctx: Context<SetAlphaMerkleRoot>,
+        merkle_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::creator::set_merkle_root::set_alpha_merkle_root(ctx, merkle_root)
+    }
+
+    pub fn claim_creator_fees(
+        ctx: Context<ClaimCreatorFees>
+    ) -> Result<()> {
+        instructions::creator::claim_creator_fees::claim_creator_fees(ctx)
+    }
+
     //  USER (Alpha Vault) 
     pub fn deposit_alpha(
         ctx: Context<DepositAlpha>, 
+        sol_amount: u64,
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> Result<()> {
+        instructions::user::deposit::deposit_alpha(ctx, sol_amount, allowlist_proof)
+    }
+
+    pub fn withdraw_alpha(
+        ctx: Context<WithdrawAlpha>, 
         sol_amount: u64
     ) -> Result<()> {
-        instructions::user::deposit::deposit_alpha(ctx, sol_amount)
+        instructions::user::withdraw::withdraw_alpha(ctx, sol_amount)
     }
 
     pub fn claim_alpha(
-        ctx: Context<ClaimAlpha>
+        ctx: Context<ClaimAlpha>,
+        close_deposit: bool,
+    ) -> Result<()> {
+        instructions::user::claim::claim_alpha(ctx, close_deposit)
+    }
+
+    pub fn finalize_alpha(
+        ctx: Context<FinalizeAlpha>
+    ) -> Result<()> {
+        instructions::user::finalize::finalize_alpha(ctx)
+    }
+
+    pub fn refund_alpha(
+        ctx: Context<RefundAlpha>
+    ) -> Result<()> {
+        instructions::user::refund::refund_alpha(ctx)
+    }
+
+    pub fn close_alpha_deposit(
+        ctx: Context<CloseAlphaDeposit>
+    ) -> Result<()> {
+        instructions::user::close_deposit::close_alpha_deposit(ctx)
+    }
+
+    pub fn distribute_alpha<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeAlpha<'info>>
     ) -> Result<()> {
-        instructions::user::claim::claim_alpha(ctx)
+        instructions::user::distribute::distribute_alpha(ctx)
+    }
+
+    pub fn get_alpha_clearing_price(
+        ctx: Context<GetAlphaClearingPrice>
+    ) -> Result<u64> {
+        instructions::user::clearing_price::get_alpha_clearing_price(ctx)
     }
 
     // USER (Trading)
     pub fn buy_tokens(
         ctx: Context<BuyTokens>, 
-        sol_amount: u64
+        sol_amount: u64,
+        min_tokens_out: u64
     ) -> Result<()> {
-        instructions::user::buy::buy_tokens(ctx, sol_amount)
+        instructions::user::buy::buy_tokens(ctx, sol_amount, min_tokens_out)
+    }
+
+    pub fn buy_exact_tokens(
+        ctx: Context<BuyTokens>, 
+        token_amount: u64,
+        max_sol_in: u64
+    ) -> Result<()> {
+        instructions::user::buy::buy_exact_tokens(ctx, token_amount, max_sol_in)
     }
 
     pub fn sell_tokens(
@@ -75,4 +210,51 @@ pub mod memelab_dex {
     ) -> Result<()> {
         instructions::user::sell::sell_tokens(ctx, token_amount, min_sol_out)
     }
+
+    // USER (Referrals)
+    pub fn register_referrer(
+        ctx: Context<RegisterReferrer>
+    ) -> Result<()> {
+        instructions::user::referrer::register_referrer(ctx)
+    }
+
+    pub fn claim_referral_fees(
+        ctx: Context<ClaimReferralFees>
+    ) -> Result<()> {
+        instructions::user::referrer::claim_referral_fees(ctx)
+    }
+
+    // POOL (Post-Graduation AMM)
+    pub fn migrate_to_pool(
+        ctx: Context<MigrateToPool>
+    ) -> Result<()> {
+        instructions::pool::migrate::migrate_to_pool(ctx)
+    }
+
+    pub fn pool_swap(
+        ctx: Context<PoolSwap>,
+        amount_in: u64,
+        min_amount_out: u64,
+        is_buy: bool
+    ) -> Result<()> {
+        instructions::pool::swap::pool_swap(ctx, amount_in, min_amount_out, is_buy)
+    }
+
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        sol_amount: u64,
+        max_token_amount: u64,
+        min_lp_out: u64
+    ) -> Result<()> {
+        instructions::pool::add_liquidity::add_liquidity(ctx, sol_amount, max_token_amount, min_lp_out)
+    }
+
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        lp_amount: u64,
+        min_sol_out: u64,
+        min_tokens_out: u64
+    ) -> Result<()> {
+        instructions::pool::remove_liquidity::remove_liquidity(ctx, lp_amount, min_sol_out, min_tokens_out)
+    }
 }
\ No newline at end of file
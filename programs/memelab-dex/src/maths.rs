@@ -64,4 +64,218 @@ pub fn calculate_sol_out(
         .ok_or(ErrorCode::MathUnderflow)?;
 
     Ok(sol_out as u64)
-}
\ No newline at end of file
+}
+
+pub fn calculate_sol_in(
+    token_amount_out: u64,
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+) -> Result<u64> {
+    // 1. Calculate k
+    let virtual_sol_u128 = virtual_sol_reserves as u128;
+    let virtual_token_u128 = virtual_token_reserves as u128;
+
+    let k = virtual_sol_u128
+        .checked_mul(virtual_token_u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // 2. Calculate new virtual tokens (must leave something in the curve)
+    let new_virtual_tokens = virtual_token_u128
+        .checked_sub(token_amount_out as u128)
+        .ok_or(ErrorCode::InsufficientTokenReserves)?;
+    require!(new_virtual_tokens > 0, ErrorCode::InsufficientTokenReserves);
+
+    // 3. Calculate new virtual SOL, rounded up so the buyer never underpays
+    let new_virtual_sol = k
+        .checked_add(new_virtual_tokens - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(new_virtual_tokens)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // 4. SOL input = new_sol - old_sol
+    let sol_in = new_virtual_sol
+        .checked_sub(virtual_sol_u128)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    u64::try_from(sol_in).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+pub fn calculate_fee(amount: u64, fee_bps: u64) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(fee as u64)
+}
+
+pub fn calculate_gross_amount(net_amount: u64, total_fee_bps: u64) -> Result<u64> {
+    // gross = ceil(net * 10000 / (10000 - bps)). Fees are floored per-recipient,
+    // so gross - fees is always >= net.
+    let denominator = 10000u128
+        .checked_sub(total_fee_bps as u128)
+        .filter(|d| *d > 0)
+        .ok_or(ErrorCode::FeeCalculationError)?;
+
+    let gross = (net_amount as u128)
+        .checked_mul(10000)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(denominator - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(denominator)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    u64::try_from(gross).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Fixed-point scale for prices: lamports per token base unit * 1e12
+pub const PRICE_SCALE: u128 = 1_000_000_000_000;
+
+pub fn calculate_clearing_price(sol_amount: u64, token_amount: u64) -> Result<u64> {
+    // price = sol * PRICE_SCALE / tokens, i.e. the average price of one fill
+    let price = (sol_amount as u128)
+        .checked_mul(PRICE_SCALE)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(token_amount as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    u64::try_from(price).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+pub fn calculate_swap_out(
+    amount_in: u64,
+    reserve_in: u64,
+    reserve_out: u64,
+    fee_bps: u64,
+) -> Result<u64> {
+    // Fee stays in the pool: out = reserve_out * in_after_fee / (reserve_in + in_after_fee)
+    let amount_in_after_fee = (amount_in as u128)
+        .checked_mul(10000u128.checked_sub(fee_bps as u128).ok_or(ErrorCode::FeeCalculationError)?)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let numerator = (reserve_out as u128)
+        .checked_mul(amount_in_after_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let denominator = (reserve_in as u128)
+        .checked_add(amount_in_after_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let amount_out = numerator
+        .checked_div(denominator)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(amount_out as u64)
+}
+
+pub fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton's method
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Initial virtual reserves and a couple of later curve states
+    const RESERVES: [(u64, u64); 3] = [
+        (30_000_000_000, 1_000_000_000_000_000),
+        (42_500_000_000, 705_882_352_941_177),
+        (85_000_000_000, 352_941_176_470_589),
+    ];
+
+    // (platform, creator, sniper) bps, each floored separately like buy_exact_tokens
+    const FEE_SPLITS: [(u64, u64, u64); 5] = [
+        (0, 0, 0),
+        (100, 0, 0),
+        (150, 500, 0),
+        (33, 77, 4999),
+        (1000, 1000, 5000),
+    ];
+
+    fn net_after_fees(gross: u64, (platform, creator, sniper): (u64, u64, u64)) -> u64 {
+        gross
+            - calculate_fee(gross, platform).unwrap()
+            - calculate_fee(gross, creator).unwrap()
+            - calculate_fee(gross, sniper).unwrap()
+    }
+
+    #[test]
+    fn gross_amount_covers_net_after_fees() {
+        for net in [1u64, 2, 3, 99, 10_001, 123_456_789, 1_000_000_007, 85_000_000_000] {
+            for split in FEE_SPLITS {
+                let total_bps = split.0 + split.1 + split.2;
+                let gross = calculate_gross_amount(net, total_bps).unwrap();
+
+                assert!(net_after_fees(gross, split) >= net, "net {net}, split {split:?}");
+                // Never more than the exact gross-up plus rounding
+                assert!(gross as u128 * (10000 - total_bps as u128) < (net as u128 + 3) * 10000);
+            }
+        }
+    }
+
+    #[test]
+    fn gross_amount_rejects_full_fee() {
+        assert!(calculate_gross_amount(1, 10000).is_err());
+        assert!(calculate_gross_amount(1, 10001).is_err());
+    }
+
+    #[test]
+    fn exact_buy_never_underfills() {
+        for (virtual_sol, virtual_token) in RESERVES {
+            for token_amount in [1u64, 7, 1_000, 1_000_000, 123_456_789_012, 100_000_000_000_000] {
+                let sol_needed = calculate_sol_in(token_amount, virtual_sol, virtual_token).unwrap();
+
+                // The exact SOL cost buys at least token_amount back
+                assert!(calculate_tokens_out(sol_needed, virtual_sol, virtual_token).unwrap() >= token_amount);
+
+                for split in FEE_SPLITS {
+                    let gross = calculate_gross_amount(sol_needed, split.0 + split.1 + split.2).unwrap();
+                    let sol_for_tokens = net_after_fees(gross, split);
+
+                    let tokens_out = calculate_tokens_out(sol_for_tokens, virtual_sol, virtual_token).unwrap();
+                    assert!(
+                        tokens_out >= token_amount,
+                        "reserves ({virtual_sol}, {virtual_token}), tokens {token_amount}, split {split:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn integer_sqrt_small_values() {
+        assert_eq!(integer_sqrt(0), 0);
+        assert_eq!(integer_sqrt(1), 1);
+        assert_eq!(integer_sqrt(2), 1);
+        assert_eq!(integer_sqrt(3), 1);
+        assert_eq!(integer_sqrt(4), 2);
+    }
+
+    #[test]
+    fn integer_sqrt_rounds_down_around_perfect_squares() {
+        for root in [10u128, 1_000_000, u64::MAX as u128] {
+            assert_eq!(integer_sqrt(root * root - 1), root - 1);
+            assert_eq!(integer_sqrt(root * root), root);
+            assert_eq!(integer_sqrt(root * root + 1), root);
+        }
+    }
+
+    #[test]
+    fn integer_sqrt_max_value() {
+        assert_eq!(integer_sqrt(u128::MAX), u64::MAX as u128);
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+// Domain separation so an inner node can never be passed off as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf for an allowlisted wallet: sha256(0x00 || wallet || max_amount as u64 LE).
+/// max_amount = 0 means the wallet has no individual cap.
+pub fn allowlist_leaf(wallet: &Pubkey, max_amount: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, wallet.as_ref(), &max_amount.to_le_bytes()]).to_bytes()
+}
+
+/// Verifies a proof against the root, hashing each pair in sorted order:
+/// sha256(0x01 || min(a, b) || max(a, b)).
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[NODE_PREFIX, &node, sibling]).to_bytes()
+        } else {
+            hashv(&[NODE_PREFIX, sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[NODE_PREFIX, &lo, &hi]).to_bytes()
+    }
+
+    struct Tree {
+        wallets: [Pubkey; 4],
+        leaves: [[u8; 32]; 4],
+        inner: [[u8; 32]; 2],
+        root: [u8; 32],
+    }
+
+    fn tree() -> Tree {
+        let wallets = [0; 4].map(|_| Pubkey::new_unique());
+        let leaves = [
+            allowlist_leaf(&wallets[0], 0),
+            allowlist_leaf(&wallets[1], 1_000),
+            allowlist_leaf(&wallets[2], 0),
+            allowlist_leaf(&wallets[3], 5_000),
+        ];
+        let inner = [node(leaves[0], leaves[1]), node(leaves[2], leaves[3])];
+        Tree { wallets, leaves, inner, root: node(inner[0], inner[1]) }
+    }
+
+    #[test]
+    fn accepts_valid_proofs() {
+        let Tree { leaves, inner, root, .. } = tree();
+
+        assert!(verify_proof(&[leaves[1], inner[1]], root, leaves[0]));
+        assert!(verify_proof(&[leaves[2], inner[0]], root, leaves[3]));
+
+        // A single-leaf tree's root is the leaf itself
+        assert!(verify_proof(&[], leaves[0], leaves[0]));
+    }
+
+    #[test]
+    fn rejects_wrong_proof() {
+        let Tree { leaves, inner, root, .. } = tree();
+
+        // Wrong sibling, missing level, tampered node
+        assert!(!verify_proof(&[leaves[2], inner[1]], root, leaves[0]));
+        assert!(!verify_proof(&[leaves[1]], root, leaves[0]));
+
+        let mut tampered = inner[1];
+        tampered[0] ^= 1;
+        assert!(!verify_proof(&[leaves[1], tampered], root, leaves[0]));
+    }
+
+    #[test]
+    fn rejects_wrong_leaf() {
+        let Tree { wallets, leaves, inner, root } = tree();
+        let proof = [leaves[0], inner[1]];
+
+        assert!(verify_proof(&proof, root, allowlist_leaf(&wallets[1], 1_000)));
+
+        // Same wallet claiming a bigger cap, or a wallet not in the tree
+        assert!(!verify_proof(&proof, root, allowlist_leaf(&wallets[1], 2_000)));
+        assert!(!verify_proof(&proof, root, allowlist_leaf(&Pubkey::new_unique(), 1_000)));
+    }
+
+    #[test]
+    fn rejects_empty_proof_against_inner_root() {
+        let Tree { leaves, inner, root, .. } = tree();
+
+        for leaf in leaves {
+            assert!(!verify_proof(&[], root, leaf));
+            assert!(!verify_proof(&[], inner[0], leaf));
+        }
+    }
+}
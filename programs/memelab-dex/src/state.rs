@@ -1,17 +1,28 @@
 use anchor_lang::prelude::*;
 
+// Layout note: fields were added in the middle of PlatformConfig and
+// TokenBondingCurve, so accounts written by earlier builds don't deserialize.
+// Upgrading an existing deployment needs a fresh program id or a migration.
 #[account]
 #[derive(InitSpace)]
 pub struct PlatformConfig {
     pub platform_authority: Pubkey,          // 32
+    pub pending_authority: Pubkey,           // 32 (default = no transfer pending)
     pub platform_fee_wallet: Pubkey,         // 32
     pub platform_fee_bps: u16,               // 2
+    pub max_creator_fee_bps: u16,            // 2 (upper bound for per-token creator fees)
+    pub referral_fee_bps: u16,               // 2 (referrer's share of the platform fee)
+    pub max_sniper_fee_bps: u16,             // 2 (upper bound for per-token anti-sniper fees)
+    pub max_sniper_fee_duration_seconds: i64, // 8 (upper bound for the anti-sniper decay window)
     pub platform_token_creation_fee: u64,    // 8
+    pub completion_sol_threshold: u64,       // 8 (0 = no SOL threshold)
+    pub completion_token_threshold: u64,     // 8
     pub total_tokens_created: u64,           // 8
+    pub paused: bool,                        // 1
     pub bump: u8,                            // 1
 }
 
-// Use zero_copy to avoid stack overflow
+// Use zero_copy to avoid stack overflow (see the layout note above)
 #[account(zero_copy)]
 #[repr(C)]
 pub struct TokenBondingCurve {
@@ -24,27 +35,212 @@ pub struct TokenBondingCurve {
     pub real_sol_reserves: u64,              // 8
     pub real_token_reserves: u64,            // 8
     
+    // Trading fees held in the curve PDA until claimed
+    pub accrued_platform_fees: u64,          // 8
+    pub accrued_creator_fees: u64,           // 8
+    
+    pub alpha_phase_start_time: i64,         // 8 (deposits open)
     pub alpha_phase_end_time: i64,           // 8
     pub total_alpha_sol: u64,                // 8
     pub total_alpha_token: u64,              // 8
+    pub alpha_min_raise: u64,                // 8 (0 = no minimum)
+    pub alpha_hard_cap: u64,                 // 8 (0 = no cap)
+    pub alpha_sol_used: u64,                 // 8 (deposits converted at finalize, <= hard cap)
+    pub alpha_clearing_price: u64,           // 8 (alpha_sol_used / total_alpha_token, scaled by maths::PRICE_SCALE)
+    pub alpha_min_deposit: u64,              // 8 (per-wallet minimum, 0 = none)
+    pub alpha_max_deposit: u64,              // 8 (per-wallet maximum, 0 = none)
+    pub alpha_merkle_root: [u8; 32],         // 32 (allowlist root, all zero = open to everyone)
+    pub alpha_vesting_cliff_seconds: i64,    // 8 (nothing unlocks before trading_live_at + cliff)
+    pub alpha_vesting_duration_seconds: i64, // 8 (linear unlock from trading_live_at, 0 = no vesting)
     pub real_token_supply: u64,              // 8
     
     pub created_at: i64,                     // 8
+    pub trading_live_at: i64,                // 8 (0 until finalize)
+    pub sniper_fee_duration_seconds: i64,    // 8 (anti-sniper fee decays linearly over this window)
+    
+    // Snapshotted from PlatformConfig at creation
+    pub completion_sol_threshold: u64,       // 8 (0 = no SOL threshold)
+    pub completion_token_threshold: u64,     // 8
     
     pub creator_fee_bps: u16,                // 2
+    pub alpha_withdraw_penalty_bps: u16,     // 2
+    pub sniper_fee_bps: u16,                 // 2 (extra buy fee at trading_live_at, decays to 0)
+    pub sniper_fee_destination: u8,          // 1 (SniperFeeDestination as u8)
     pub trading_live: u8,                    // 1 (0 = false, 1 = true)
     pub is_complete: u8,                     // 1 (0 = false, 1 = true)
     pub bump: u8,                            // 1
+    pub is_migrated: u8,                     // 1 (0 = false, 1 = true)
+    pub paused: u8,                          // 1 (0 = false, 1 = true)
+    pub alpha_failed: u8,                    // 1 (0 = false, 1 = true)
     pub _padding: [u8; 3],                   // 3 (padding to align to 8 bytes)
 }
 
+impl TokenBondingCurve {
+    /// Anti-sniper fee in bps at current_time: starts at sniper_fee_bps when
+    /// trading goes live and decays linearly to 0 over the window.
+    pub fn current_sniper_fee_bps(&self, current_time: i64) -> u64 {
+        let elapsed = current_time.saturating_sub(self.trading_live_at);
+        if self.trading_live == 0 || elapsed >= self.sniper_fee_duration_seconds {
+            return 0;
+        }
+
+        let remaining = (self.sniper_fee_duration_seconds - elapsed) as u64;
+        self.sniper_fee_bps as u64 * remaining / self.sniper_fee_duration_seconds as u64
+    }
+
+    /// True once the alpha phase has ended below its minimum raise,
+    /// whether or not finalize_alpha has recorded it yet.
+    pub fn alpha_raise_failed(&self, current_time: i64) -> bool {
+        self.alpha_failed == 1
+            || (self.trading_live == 0
+                && current_time >= self.alpha_phase_end_time
+                && self.total_alpha_sol < self.alpha_min_raise)
+    }
+
+    /// Portion of an alpha allocation unlocked at current_time under the
+    /// curve's cliff + linear vesting schedule.
+    pub fn alpha_unlocked(&self, allocation: u64, current_time: i64) -> u64 {
+        if self.trading_live == 0 {
+            return 0;
+        }
+
+        let elapsed = current_time.saturating_sub(self.trading_live_at);
+        if elapsed < self.alpha_vesting_cliff_seconds {
+            return 0;
+        }
+        if elapsed >= self.alpha_vesting_duration_seconds {
+            return allocation;
+        }
+
+        // elapsed < duration here, so the result is below allocation
+        ((allocation as u128 * elapsed as u128) / self.alpha_vesting_duration_seconds as u128) as u64
+    }
+}
+
+/// Where the anti-sniper fee goes. Stored on the curve as a u8.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SniperFeeDestination {
+    Creator = 0,
+    Platform = 1,
+    Curve = 2,      // Added to real SOL reserves
+}
+
+impl SniperFeeDestination {
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            0 => SniperFeeDestination::Creator,
+            1 => SniperFeeDestination::Platform,
+            _ => SniperFeeDestination::Curve,
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserAlphaDeposit {
     pub user: Pubkey,           // 32
     pub mint: Pubkey,           // 32
     pub sol_amount: u64,        // 8
-    pub tokens_owed: u64,       // 8
+    pub tokens_owed: u64,       // 8 (total allocation, set on first claim)
+    pub tokens_claimed: u64,    // 8 (vested tokens already transferred)
+    pub sol_refunded: u64,      // 8 (oversubscription refund paid on claim)
+    pub penalty_paid: u64,      // 8 (withdraw penalties, returned by refund_alpha if the launch fails)
     pub claimed: bool,          // 1
+    pub refunded: bool,         // 1
+    pub bump: u8,               // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub wallet: Pubkey,         // 32
+    pub total_volume: u64,      // 8 (SOL traded through this referrer, before fees)
+    pub total_earned: u64,      // 8 (all referral fees ever accrued)
+    pub accrued_fees: u64,      // 8 (held in this PDA until claimed)
+    pub bump: u8,               // 1
+}
+
+pub const MAX_FEE_TIERS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct FeeTier {
+    pub min_volume: u64,        // 8 (lifetime SOL volume needed to qualify)
+    pub fee_bps: u16,           // 2
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSchedule {
+    #[max_len(MAX_FEE_TIERS)]
+    pub tiers: Vec<FeeTier>,    // 4 + 10 * MAX_FEE_TIERS (ascending by min_volume, fee_bps non-increasing)
+    pub bump: u8,               // 1
+}
+
+impl FeeSchedule {
+    /// Platform fee for a trader with `volume` lifetime SOL volume: the
+    /// highest tier reached, never above the flat `base_bps`.
+    pub fn fee_bps_for(&self, volume: u64, base_bps: u16) -> u16 {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| volume >= tier.min_volume)
+            .map_or(base_bps, |tier| tier.fee_bps.min(base_bps))
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TraderVolume {
+    pub trader: Pubkey,         // 32
+    pub total_volume: u64,      // 8 (SOL traded on the curve, before fees)
     pub bump: u8,               // 1
 }
+
+#[account]
+#[derive(InitSpace)]
+pub struct LiquidityPool {
+    pub token_mint: Pubkey,         // 32
+    pub lp_mint: Pubkey,            // 32
+    pub sol_reserves: u64,          // 8
+    pub token_reserves: u64,        // 8
+    pub locked_lp_supply: u64,      // 8 (migration shares, never minted)
+    pub fee_bps: u16,               // 2
+    pub bump: u8,                   // 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    fn sniper_curve(fee_bps: u16, duration_seconds: i64) -> TokenBondingCurve {
+        let mut curve = TokenBondingCurve::zeroed();
+        curve.trading_live = 1;
+        curve.trading_live_at = 1_000;
+        curve.sniper_fee_bps = fee_bps;
+        curve.sniper_fee_duration_seconds = duration_seconds;
+        curve
+    }
+
+    #[test]
+    fn sniper_fee_decays_linearly_to_zero() {
+        let curve = sniper_curve(2000, 60);
+
+        assert_eq!(curve.current_sniper_fee_bps(1_000), 2000);
+        assert_eq!(curve.current_sniper_fee_bps(1_030), 1000);
+        assert_eq!(curve.current_sniper_fee_bps(1_045), 500);
+        assert_eq!(curve.current_sniper_fee_bps(1_059), 33);
+        assert_eq!(curve.current_sniper_fee_bps(1_060), 0);
+        assert_eq!(curve.current_sniper_fee_bps(2_000), 0);
+    }
+
+    #[test]
+    fn sniper_fee_is_zero_before_trading_or_without_a_window() {
+        let mut curve = sniper_curve(2000, 60);
+        curve.trading_live = 0;
+        assert_eq!(curve.current_sniper_fee_bps(1_000), 0);
+
+        let curve = sniper_curve(2000, 0);
+        assert_eq!(curve.current_sniper_fee_bps(1_000), 0);
+    }
+}
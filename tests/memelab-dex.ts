@@ -5,6 +5,7 @@ import {
   PublicKey, 
   Keypair, 
   SystemProgram, 
+  Transaction,
   LAMPORTS_PER_SOL
 } from "@solana/web3.js";
 import { 
@@ -12,6 +13,7 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { createHash } from "crypto";
 
 describe("memelab_dex", () => {
   const provider = anchor.AnchorProvider.env();
@@ -36,6 +38,220 @@ describe("memelab_dex", () => {
   
   const MPL_TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 
+  const traderVolumePda = (trader: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("trader_volume"), trader.toBuffer()], program.programId)[0];
+
+  const NO_SNIPER_FEE = { feeBps: 0, durationSeconds: new anchor.BN(0), destination: { creator: {} } };
+
+  type CreateTokenArgs = Parameters<typeof program.methods.createToken>;
+
+  // Open, unvested alpha vault with no limits
+  const DEFAULT_ALPHA_VAULT: CreateTokenArgs[5] = {
+    startTime: new anchor.BN(0),
+    withdrawPenaltyBps: 0,
+    minRaise: new anchor.BN(0),
+    hardCap: new anchor.BN(0),
+    minDeposit: new anchor.BN(0),
+    maxDeposit: new anchor.BN(0),
+    merkleRoot: Array(32).fill(0),
+    vestingCliffSeconds: new anchor.BN(0),
+    vestingDurationSeconds: new anchor.BN(0),
+  };
+
+  const tokenAccountFor = (owner: PublicKey, mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    )[0];
+
+  const alphaDepositPda = (user: PublicKey, mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("alpha_deposit"), user.toBuffer(), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  // Launches a fresh token from `creator` with a 2s alpha phase. Any
+  // createToken argument can be overridden; alphaVault fields are merged
+  // over DEFAULT_ALPHA_VAULT.
+  const createTestToken = async (
+    overrides: {
+      symbol?: string;
+      creatorFeeBps?: number;
+      alphaDurationSeconds?: number;
+      alphaVault?: Partial<CreateTokenArgs[5]>;
+      sniperFee?: CreateTokenArgs[6];
+    } = {}
+  ) => {
+    const mint = Keypair.generate();
+    const [curve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const vault = tokenAccountFor(curve, mint.publicKey);
+    const [metadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.publicKey.toBuffer()],
+      MPL_TOKEN_METADATA_PROGRAM_ID
+    );
+    const symbol = overrides.symbol ?? "TEST";
+
+    await program.methods
+      .createToken(
+        `${symbol} Coin`,
+        symbol,
+        `https://example.com/${symbol.toLowerCase()}.json`,
+        overrides.creatorFeeBps ?? 0,
+        new anchor.BN(overrides.alphaDurationSeconds ?? 2),
+        { ...DEFAULT_ALPHA_VAULT, ...overrides.alphaVault },
+        overrides.sniperFee ?? NO_SNIPER_FEE
+      )
+      .accounts({
+        creator: creator.publicKey,
+        platformConfig: platformConfig,
+        platformFeeWallet: feeWallet.publicKey,
+        mint: mint.publicKey,
+        bondingCurve: curve,
+        bondingCurveTokenAccount: vault,
+        metadataAccount: metadata,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        metadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([creator, mint])
+      .rpc();
+
+    return { mint, curve, vault };
+  };
+
+  type TestToken = { mint: Keypair; curve: PublicKey; vault: PublicKey };
+
+  // buy/sell accounts for a test token, without a referrer or fee schedule
+  const tradeAccounts = (trader: PublicKey, token: TestToken) => ({
+    bondingCurve: token.curve,
+    mint: token.mint.publicKey,
+    platformConfig: platformConfig,
+    bondingCurveTokenAccount: token.vault,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+    referrer: null,
+    feeSchedule: null,
+    traderVolume: traderVolumePda(trader),
+  });
+
+  const buyAccounts = (buyer: PublicKey, token: TestToken) => ({
+    ...tradeAccounts(buyer, token),
+    buyer,
+    buyerTokenAccount: tokenAccountFor(buyer, token.mint.publicKey),
+  });
+
+  const sellAccounts = (seller: PublicKey, token: TestToken) => ({
+    ...tradeAccounts(seller, token),
+    seller,
+    sellerTokenAccount: tokenAccountFor(seller, token.mint.publicKey),
+  });
+
+  const poolAccountsFor = (token: TestToken) => {
+    const [pool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pool"), token.mint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [lpMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("lp_mint"), token.mint.publicKey.toBuffer()],
+      program.programId
+    );
+    return { pool, lpMint, poolTokenAccount: tokenAccountFor(pool, token.mint.publicKey) };
+  };
+
+  const migrate = (token: TestToken) =>
+    program.methods
+      .migrateToPool()
+      .accounts({
+        payer: admin.publicKey,
+        bondingCurve: token.curve,
+        mint: token.mint.publicKey,
+        bondingCurveTokenAccount: token.vault,
+        ...poolAccountsFor(token),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+
+  // Launches a token with a 0.05 SOL threshold and has User A buy it out
+  const createCompletedToken = async (symbol: string) => {
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    await updatePlatformConfig({ completionSolThreshold: new anchor.BN(0.05 * LAMPORTS_PER_SOL) });
+    const token = await createTestToken({ symbol });
+    await updatePlatformConfig({ completionSolThreshold: config.completionSolThreshold });
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    await program.methods
+      .buyTokens(new anchor.BN(0.2 * LAMPORTS_PER_SOL), new anchor.BN(0))
+      .accounts(buyAccounts(userA.publicKey, token))
+      .signers([userA])
+      .rpc();
+    return token;
+  };
+
+  // Events from emit_cpi!: each is a self-invoke whose data is an 8-byte
+  // event ix tag followed by the encoded event
+  const cpiEvents = async (signature: string) => {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const accountKeys = tx.transaction.message.getAccountKeys();
+
+    return tx.meta.innerInstructions
+      .flatMap((inner) => inner.instructions)
+      .filter((ix) => accountKeys.get(ix.programIdIndex).equals(program.programId))
+      .map((ix) => {
+        const data = anchor.utils.bytes.bs58.decode(ix.data);
+        return program.coder.events.decode(anchor.utils.bytes.base64.encode(data.subarray(8)));
+      })
+      .filter((event) => event !== null);
+  };
+
+  const findEvent = (events: { name: string; data: any }[], name: string) =>
+    events.find((event) => event.name.toLowerCase() === name.toLowerCase());
+
+  // Admin update that leaves every field not listed unchanged
+  const updatePlatformConfig = async (
+    changes: Partial<Parameters<typeof program.methods.updatePlatformConfig>[0]>
+  ) => {
+    await program.methods
+      .updatePlatformConfig({
+        platformFeeWallet: null,
+        platformFeeBps: null,
+        maxCreatorFeeBps: null,
+        referralFeeBps: null,
+        maxSniperFeeBps: null,
+        maxSniperFeeDurationSeconds: null,
+        platformTokenCreationFee: null,
+        completionSolThreshold: null,
+        completionTokenThreshold: null,
+        ...changes,
+      })
+      .accounts({
+        platformConfig: platformConfig,
+        authority: admin.publicKey,
+      })
+      .rpc();
+  };
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+    } catch (err) {
+      assert.include(err.toString(), code);
+      return;
+    }
+    assert.fail(`Expected ${code}`);
+  };
+
   before(async () => {
     console.log("\n Starting MemeLab DEX Tests\n");
     
@@ -97,7 +313,12 @@ describe("memelab_dex", () => {
         .initializePlatform(
           feeWallet.publicKey,
           100, // 1% Fee (100 bps)
-          new anchor.BN(LAMPORTS_PER_SOL / 100) // 0.01 SOL creation fee
+          500, // Creators can charge up to 5%
+          5000, // Anti-sniper fees up to 50%
+          new anchor.BN(300), // decaying over at most 5 minutes
+          new anchor.BN(LAMPORTS_PER_SOL / 100), // 0.01 SOL creation fee
+          new anchor.BN(85 * LAMPORTS_PER_SOL), // Complete at 85 SOL raised
+          new anchor.BN(0) // No token reserve threshold
         )
         .accounts({
           platformConfig: platformConfig,
@@ -109,6 +330,7 @@ describe("memelab_dex", () => {
       const config = await program.account.platformConfig.fetch(platformConfig);
       assert.ok(config.platformAuthority.equals(admin.publicKey));
       assert.equal(config.platformFeeBps, 100);
+      assert.equal(config.completionSolThreshold.toString(), (85 * LAMPORTS_PER_SOL).toString());
       console.log(" Platform Initialized");
       console.log("   Fee: 1% (100 bps)");
       console.log("   Creation Fee: 0.01 SOL");
@@ -135,16 +357,32 @@ describe("memelab_dex", () => {
       MPL_TOKEN_METADATA_PROGRAM_ID
     );
 
+    const feeWalletBefore = await provider.connection.getBalance(feeWallet.publicKey);
+
     await program.methods
       .createToken(
         "MemeCoin",
         "MEME",
         "https://example.com/meme.json",
-        alphaDuration
+        100, // 1% creator fee
+        alphaDuration,
+        {
+          startTime: new anchor.BN(0), // Deposits open immediately
+          withdrawPenaltyBps: 100, // 1% early-exit penalty
+          minRaise: new anchor.BN(0), // No minimum raise
+          hardCap: new anchor.BN(0), // No hard cap
+          minDeposit: new anchor.BN(0), // No per-wallet minimum
+          maxDeposit: new anchor.BN(2 * LAMPORTS_PER_SOL), // 2 SOL per wallet
+          merkleRoot: Array(32).fill(0), // Open to everyone
+          vestingCliffSeconds: new anchor.BN(0), // No vesting
+          vestingDurationSeconds: new anchor.BN(0),
+        },
+        NO_SNIPER_FEE // No anti-sniper fee
       )
       .accounts({
         creator: creator.publicKey,
         platformConfig: platformConfig,
+        platformFeeWallet: feeWallet.publicKey,
         mint: mintKeypair.publicKey,
         bondingCurve: bondingCurve,
         bondingCurveTokenAccount: bondingCurveVault,
@@ -158,6 +396,14 @@ describe("memelab_dex", () => {
       .signers([creator, mintKeypair])
       .rpc();
 
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    const feeWalletAfter = await provider.connection.getBalance(feeWallet.publicKey);
+    assert.equal(
+      feeWalletAfter - feeWalletBefore,
+      config.platformTokenCreationFee.toNumber(),
+      "Creation fee should be paid to the platform fee wallet"
+    );
+
     const curve = await program.account.tokenBondingCurve.fetch(bondingCurve);
     
     // zero_copy stores bool as u8: 0 = false, 1 = true
@@ -166,6 +412,8 @@ describe("memelab_dex", () => {
     assert.ok(curve.creator.equals(creator.publicKey));
     assert.equal(curve.totalAlphaSol.toString(), "0");
     assert.equal(curve.totalAlphaToken.toString(), "0");
+    assert.equal(curve.isComplete, 0, "Curve should not be complete");
+    assert.equal(curve.completionSolThreshold.toString(), (85 * LAMPORTS_PER_SOL).toString());
     
     console.log(" Token Created (Alpha Phase Active)");
     console.log("   Name: MemeCoin (MEME)");
@@ -179,11 +427,12 @@ describe("memelab_dex", () => {
     const depositAmount = new anchor.BN(1 * LAMPORTS_PER_SOL);
 
     await program.methods
-      .depositAlpha(depositAmount)
+      .depositAlpha(depositAmount, null)
       .accounts({
         user: userA.publicKey,
         bondingCurve: bondingCurve,
         mint: mintKeypair.publicKey,
+        platformConfig: platformConfig,
         userDeposit: userADeposit,
         systemProgram: SystemProgram.programId,
       })
@@ -205,6 +454,50 @@ describe("memelab_dex", () => {
     console.log("   Claimed:", depositState.claimed ? "Yes" : "No");
   });
 
+  it("3b. User A Withdraws Part Of Alpha Deposit (1% Penalty)", async () => {
+    const withdrawAmount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+    const depositBefore = await program.account.userAlphaDeposit.fetch(userADeposit);
+    const curveBefore = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    const userBalanceBefore = await provider.connection.getBalance(userA.publicKey);
+    const curveBalanceBefore = await provider.connection.getBalance(bondingCurve);
+
+    await program.methods
+      .withdrawAlpha(withdrawAmount)
+      .accounts({
+        user: userA.publicKey,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userADeposit,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userA])
+      .rpc();
+
+    const depositAfter = await program.account.userAlphaDeposit.fetch(userADeposit);
+    const curveAfter = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    const userBalanceAfter = await provider.connection.getBalance(userA.publicKey);
+    const curveBalanceAfter = await provider.connection.getBalance(bondingCurve);
+    const penalty = withdrawAmount.muln(100).divn(10000);
+    const payout = withdrawAmount.sub(penalty).toNumber();
+
+    assert.equal(depositAfter.solAmount.toString(), depositBefore.solAmount.sub(withdrawAmount).toString());
+    assert.equal(curveAfter.totalAlphaSol.toString(), curveBefore.totalAlphaSol.sub(withdrawAmount).toString());
+    assert.equal(curveAfter.realSolReserves.toString(), curveBefore.realSolReserves.add(penalty).toString());
+    assert.equal(
+      curveAfter.virtualSolReserves.toString(),
+      curveBefore.virtualSolReserves.toString(),
+      "Penalty should not move the price"
+    );
+
+    // The provider pays the tx fee, so balances move by exactly the payout
+    assert.equal(userBalanceAfter - userBalanceBefore, payout, "User should get the deposit minus the penalty");
+    assert.equal(curveBalanceBefore - curveBalanceAfter, payout, "Penalty lamports should stay in the curve");
+
+    console.log(" User A Withdrew 0.1 SOL From Alpha Vault");
+    console.log("   Penalty kept in curve:", penalty.toString(), "lamports");
+  });
+
   it("4. User B Tries to Buy (Should FAIL - Anti-Snipe)", async () => {
     try {
       const buyAmount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
@@ -218,7 +511,7 @@ describe("memelab_dex", () => {
       );
 
       await program.methods
-        .buyTokens(buyAmount)
+        .buyTokens(buyAmount, new anchor.BN(0))
         .accounts({
           buyer: userB.publicKey,
           bondingCurve: bondingCurve,
@@ -226,11 +519,12 @@ describe("memelab_dex", () => {
           platformConfig: platformConfig,
           bondingCurveTokenAccount: bondingCurveVault,
           buyerTokenAccount: userBTokenAccount,
-          platformFeeWallet: feeWallet.publicKey,
-          creatorFeeWallet: creator.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          referrer: null,
+          feeSchedule: null,
+          traderVolume: traderVolumePda(userB.publicKey),
         })
         .signers([userB])
         .rpc();
@@ -274,7 +568,7 @@ describe("memelab_dex", () => {
     console.log("     Total Alpha Token:", curveBefore.totalAlphaToken.toString());
 
     await program.methods
-      .buyTokens(buyAmount)
+      .buyTokens(buyAmount, new anchor.BN(0))
       .accounts({
         buyer: userB.publicKey,
         bondingCurve: bondingCurve,
@@ -282,11 +576,12 @@ describe("memelab_dex", () => {
         platformConfig: platformConfig,
         bondingCurveTokenAccount: bondingCurveVault,
         buyerTokenAccount: userBTokenAccount,
-        platformFeeWallet: feeWallet.publicKey,
-        creatorFeeWallet: creator.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        referrer: null,
+        feeSchedule: null,
+        traderVolume: traderVolumePda(userB.publicKey),
       })
       .signers([userB])
       .rpc();
@@ -297,6 +592,31 @@ describe("memelab_dex", () => {
     assert.equal(curveAfter.tradingLive, 1, "Trading should be live now");
     assert.ok(curveAfter.realSolReserves.toNumber() > 0, "Should have SOL reserves");
     assert.ok(curveAfter.totalAlphaToken.toNumber() > 0, "Should have allocated alpha tokens");
+
+    // The whole vault is filled as one buy at the pre-finalization curve state
+    const k = curveBefore.virtualSolReserves.mul(curveBefore.virtualTokenReserves);
+    const expectedAlphaTokens = curveBefore.virtualTokenReserves.sub(
+      k.div(curveBefore.virtualSolReserves.add(curveAfter.alphaSolUsed))
+    );
+    assert.equal(
+      curveAfter.totalAlphaToken.toString(),
+      expectedAlphaTokens.toString(),
+      "Alpha allocation should be a single calculate_tokens_out fill"
+    );
+
+    // Every depositor pays the same clearing price (lamports per base unit * 1e12)
+    const PRICE_SCALE = new anchor.BN("1000000000000");
+    const expectedPrice = curveAfter.alphaSolUsed.mul(PRICE_SCALE).div(curveAfter.totalAlphaToken);
+    assert.equal(curveAfter.alphaClearingPrice.toString(), expectedPrice.toString());
+
+    const viewedPrice = await program.methods
+      .getAlphaClearingPrice()
+      .accounts({
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+      })
+      .view();
+    assert.equal(viewedPrice.toString(), expectedPrice.toString(), "View should return the stored price");
     
     // Check User B received tokens
     const userBBalance = await provider.connection.getTokenAccountBalance(userBTokenAccount);
@@ -306,6 +626,7 @@ describe("memelab_dex", () => {
     console.log("   After finalization:");
     console.log("     Trading Live:", curveAfter.tradingLive === 1 ? "Yes ✓" : "No");
     console.log("     Total Alpha Token:", curveAfter.totalAlphaToken.toString());
+    console.log("     Alpha Clearing Price:", curveAfter.alphaClearingPrice.toString());
     console.log("     Real SOL Reserves:", curveAfter.realSolReserves.toString());
     console.log("     Real Token Reserves:", curveAfter.realTokenReserves.toString());
     console.log("     User B Token Balance:", userBBalance.value.amount);
@@ -336,11 +657,12 @@ describe("memelab_dex", () => {
     console.log("     Expected tokens:", expectedTokens.toString());
 
     await program.methods
-      .claimAlpha()
+      .claimAlpha(false)
       .accounts({
         claimer: userA.publicKey,
         bondingCurve: bondingCurve,
         mint: mintKeypair.publicKey,
+        platformConfig: platformConfig,
         userDeposit: userADeposit,
         bondingCurveTokenAccount: bondingCurveVault,
         claimerTokenAccount: userATokenAccount,
@@ -410,11 +732,12 @@ describe("memelab_dex", () => {
         platformConfig: platformConfig,
         bondingCurveTokenAccount: bondingCurveVault,
         sellerTokenAccount: userBTokenAccount,
-        feeWallet: feeWallet.publicKey,
-        creatorWallet: creator.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        referrer: null,
+        feeSchedule: null,
+        traderVolume: traderVolumePda(userB.publicKey),
       })
       .signers([userB])
       .rpc();
@@ -436,6 +759,1823 @@ describe("memelab_dex", () => {
     console.log("   SOL received (after fees):", solDiff > 0 ? `+${solDiff} lamports` : `${solDiff} lamports (includes tx fee)`);
   });
 
+  it("9. User B Buys With Unreachable min_tokens_out (Should FAIL - Slippage)", async () => {
+    const buyAmount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+    const [userBTokenAccount] = PublicKey.findProgramAddressSync(
+      [
+        userB.publicKey.toBuffer(),
+        TOKEN_PROGRAM_ID.toBuffer(),
+        mintKeypair.publicKey.toBuffer(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const curve = await program.account.tokenBondingCurve.fetch(bondingCurve);
+
+    try {
+      await program.methods
+        .buyTokens(buyAmount, curve.realTokenReserves) // more than 0.1 SOL can ever buy
+        .accounts({
+          buyer: userB.publicKey,
+          bondingCurve: bondingCurve,
+          mint: mintKeypair.publicKey,
+          platformConfig: platformConfig,
+          bondingCurveTokenAccount: bondingCurveVault,
+          buyerTokenAccount: userBTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          referrer: null,
+          feeSchedule: null,
+          traderVolume: traderVolumePda(userB.publicKey),
+        })
+        .signers([userB])
+        .rpc();
+
+      assert.fail(" Should have failed because min_tokens_out cannot be met");
+    } catch (e) {
+      const errorFound =
+        e.message.includes("SlippageExceeded") ||
+        e.error?.errorCode?.code === "SlippageExceeded";
+
+      assert.ok(errorFound, "Should fail with SlippageExceeded error");
+      console.log(" Buy Rejected By Slippage Guard");
+    }
+  });
+
+  it("10. User B Buys An Exact Token Amount", async () => {
+    const tokenAmount = new anchor.BN(10_000_000).mul(new anchor.BN(1_000_000)); // 10M tokens
+    const [userBTokenAccount] = PublicKey.findProgramAddressSync(
+      [
+        userB.publicKey.toBuffer(),
+        TOKEN_PROGRAM_ID.toBuffer(),
+        mintKeypair.publicKey.toBuffer(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    const balanceBefore = await provider.connection.getTokenAccountBalance(userBTokenAccount);
+
+    await program.methods
+      .buyExactTokens(tokenAmount, new anchor.BN(1 * LAMPORTS_PER_SOL))
+      .accounts({
+        buyer: userB.publicKey,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        platformConfig: platformConfig,
+        bondingCurveTokenAccount: bondingCurveVault,
+        buyerTokenAccount: userBTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        referrer: null,
+        feeSchedule: null,
+        traderVolume: traderVolumePda(userB.publicKey),
+      })
+      .signers([userB])
+      .rpc();
+
+    const balanceAfter = await provider.connection.getTokenAccountBalance(userBTokenAccount);
+    const received = new anchor.BN(balanceAfter.value.amount).sub(new anchor.BN(balanceBefore.value.amount));
+
+    assert.equal(received.toString(), tokenAmount.toString(), "Should receive exactly the requested amount");
+    console.log(" User B Bought Exact Amount");
+    console.log("   Tokens received:", received.toString());
+  });
+
+  it("11. Update Platform Config (Admin Only)", async () => {
+    const params = {
+      platformFeeWallet: null,
+      platformFeeBps: 150,
+      maxCreatorFeeBps: null,
+      referralFeeBps: null,
+      maxSniperFeeBps: null,
+      maxSniperFeeDurationSeconds: null,
+      platformTokenCreationFee: null,
+      completionSolThreshold: null,
+      completionTokenThreshold: null,
+    };
+
+    try {
+      await program.methods
+        .updatePlatformConfig(params)
+        .accounts({
+          platformConfig: platformConfig,
+          authority: userA.publicKey,
+        })
+        .signers([userA])
+        .rpc();
+
+      assert.fail(" Should have failed because User A is not the platform authority");
+    } catch (e) {
+      const errorFound =
+        e.message.includes("Unauthorized") ||
+        e.error?.errorCode?.code === "Unauthorized";
+      assert.ok(errorFound, "Should fail with Unauthorized error");
+    }
+
+    await program.methods
+      .updatePlatformConfig(params)
+      .accounts({
+        platformConfig: platformConfig,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    assert.equal(config.platformFeeBps, 150);
+    assert.ok(config.platformFeeWallet.equals(feeWallet.publicKey), "Untouched fields should not change");
+
+    console.log(" Platform Config Updated");
+    console.log("   Fee: 1.5% (150 bps)");
+  });
+
+  it("12. Propose, Reject, Cancel & Accept Authority Transfer", async () => {
+    await program.methods
+      .proposeAuthority(userB.publicKey)
+      .accounts({
+        platformConfig: platformConfig,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    let config = await program.account.platformConfig.fetch(platformConfig);
+    assert.ok(config.pendingAuthority.equals(userB.publicKey));
+
+    // Only the proposed key can accept
+    try {
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          platformConfig: platformConfig,
+          newAuthority: userA.publicKey,
+        })
+        .signers([userA])
+        .rpc();
+
+      assert.fail(" Should have failed because User A is not the pending authority");
+    } catch (e) {
+      const errorFound =
+        e.message.includes("Unauthorized") ||
+        e.error?.errorCode?.code === "Unauthorized";
+      assert.ok(errorFound, "Should fail with Unauthorized error");
+    }
+
+    await program.methods
+      .cancelAuthorityTransfer()
+      .accounts({
+        platformConfig: platformConfig,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    config = await program.account.platformConfig.fetch(platformConfig);
+    assert.ok(config.platformAuthority.equals(admin.publicKey), "Authority should be unchanged");
+    assert.ok(config.pendingAuthority.equals(PublicKey.default), "Pending authority should be cleared");
+
+    // Hand control to a fresh key, which has to sign to take it
+    const newAuthority = Keypair.generate();
+    await program.methods
+      .proposeAuthority(newAuthority.publicKey)
+      .accounts({ platformConfig: platformConfig, authority: admin.publicKey })
+      .rpc();
+    await program.methods
+      .acceptAuthority()
+      .accounts({ platformConfig: platformConfig, newAuthority: newAuthority.publicKey })
+      .signers([newAuthority])
+      .rpc();
+
+    config = await program.account.platformConfig.fetch(platformConfig);
+    assert.ok(config.platformAuthority.equals(newAuthority.publicKey), "New key should hold authority");
+    assert.ok(config.pendingAuthority.equals(PublicKey.default), "Pending authority should be cleared");
+
+    // The old key is locked out
+    await expectError(
+      program.methods
+        .proposeAuthority(admin.publicKey)
+        .accounts({ platformConfig: platformConfig, authority: admin.publicKey })
+        .rpc(),
+      "Unauthorized"
+    );
+
+    // Hand it back so later tests keep using the provider wallet
+    await program.methods
+      .proposeAuthority(admin.publicKey)
+      .accounts({ platformConfig: platformConfig, authority: newAuthority.publicKey })
+      .signers([newAuthority])
+      .rpc();
+    await program.methods
+      .acceptAuthority()
+      .accounts({ platformConfig: platformConfig, newAuthority: admin.publicKey })
+      .rpc();
+
+    config = await program.account.platformConfig.fetch(platformConfig);
+    assert.ok(config.platformAuthority.equals(admin.publicKey), "Admin should hold authority again");
+
+    console.log(" Authority Transfer Proposed, Cancelled, Accepted & Returned");
+  });
+
+  it("13. Paused Token Rejects Trades", async () => {
+    const [userBTokenAccount] = PublicKey.findProgramAddressSync(
+      [
+        userB.publicKey.toBuffer(),
+        TOKEN_PROGRAM_ID.toBuffer(),
+        mintKeypair.publicKey.toBuffer(),
+      ],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    await program.methods
+      .setTokenPaused(true)
+      .accounts({
+        platformConfig: platformConfig,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    let curve = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    assert.equal(curve.paused, 1, "Token should be paused");
+
+    try {
+      await program.methods
+        .sellTokens(new anchor.BN(1_000_000), new anchor.BN(0))
+        .accounts({
+          seller: userB.publicKey,
+          bondingCurve: bondingCurve,
+          mint: mintKeypair.publicKey,
+          platformConfig: platformConfig,
+          bondingCurveTokenAccount: bondingCurveVault,
+          sellerTokenAccount: userBTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          referrer: null,
+          feeSchedule: null,
+          traderVolume: traderVolumePda(userB.publicKey),
+        })
+        .signers([userB])
+        .rpc();
+
+      assert.fail(" Should have failed because the token is paused");
+    } catch (e) {
+      const errorFound =
+        e.message.includes("TokenPaused") ||
+        e.error?.errorCode?.code === "TokenPaused";
+      assert.ok(errorFound, "Should fail with TokenPaused error");
+    }
+
+    await program.methods
+      .setTokenPaused(false)
+      .accounts({
+        platformConfig: platformConfig,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    curve = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    assert.equal(curve.paused, 0, "Token should be unpaused");
+
+    console.log(" Token Pause Switch Working");
+  });
+
+  it("13b. Paused Platform Rejects Launches, Deposits And Trades", async () => {
+    const mainToken = { mint: mintKeypair, curve: bondingCurve, vault: bondingCurveVault };
+    const openToken = await createTestToken({ symbol: "HALT", alphaDurationSeconds: 60 });
+    const setPlatformPaused = (paused: boolean) =>
+      program.methods
+        .setPlatformPaused(paused)
+        .accounts({ platformConfig: platformConfig, authority: admin.publicKey })
+        .rpc();
+
+    await setPlatformPaused(true);
+    let config = await program.account.platformConfig.fetch(platformConfig);
+    assert.isTrue(config.paused, "Platform should be paused");
+
+    await expectError(createTestToken({ symbol: "LATE" }), "PlatformPaused");
+    await expectError(
+      program.methods
+        .depositAlpha(new anchor.BN(0.01 * LAMPORTS_PER_SOL), null)
+        .accounts({
+          user: userA.publicKey,
+          bondingCurve: openToken.curve,
+          mint: openToken.mint.publicKey,
+          platformConfig: platformConfig,
+          userDeposit: alphaDepositPda(userA.publicKey, openToken.mint.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userA])
+        .rpc(),
+      "PlatformPaused"
+    );
+    await expectError(
+      program.methods
+        .buyTokens(new anchor.BN(0.01 * LAMPORTS_PER_SOL), new anchor.BN(0))
+        .accounts(buyAccounts(userB.publicKey, mainToken))
+        .signers([userB])
+        .rpc(),
+      "PlatformPaused"
+    );
+    await expectError(
+      program.methods
+        .sellTokens(new anchor.BN(1_000_000), new anchor.BN(0))
+        .accounts(sellAccounts(userB.publicKey, mainToken))
+        .signers([userB])
+        .rpc(),
+      "PlatformPaused"
+    );
+
+    await setPlatformPaused(false);
+    config = await program.account.platformConfig.fetch(platformConfig);
+    assert.isFalse(config.paused, "Platform should be unpaused");
+
+    console.log(" Platform Pause Switch Working");
+  });
+
+  it("14. Finalize Alpha Crank Rejects An Already-Live Curve", async () => {
+    try {
+      await program.methods
+        .finalizeAlpha()
+        .accounts({
+          bondingCurve: bondingCurve,
+          mint: mintKeypair.publicKey,
+        })
+        .rpc();
+
+      assert.fail(" Should have failed because trading is already live");
+    } catch (e) {
+      const errorFound =
+        e.message.includes("TradingAlreadyLive") ||
+        e.error?.errorCode?.code === "TradingAlreadyLive";
+      assert.ok(errorFound, "Should fail with TradingAlreadyLive error");
+      console.log(" Finalize Crank Is Idempotent-Safe");
+    }
+  });
+
+  it("15. Failed Launch Below Min Raise Refunds Depositors", async () => {
+    const { mint: failedMint, curve: failedCurve } = await createTestToken({
+      symbol: "FAIL",
+      alphaDurationSeconds: 4,
+      alphaVault: {
+        minRaise: new anchor.BN(5 * LAMPORTS_PER_SOL), // Needs 5 SOL to launch
+        withdrawPenaltyBps: 1000, // 10% early-exit penalty
+      },
+    });
+    const userBDeposit = alphaDepositPda(userB.publicKey, failedMint.publicKey);
+    const alphaAccounts = {
+      user: userB.publicKey,
+      bondingCurve: failedCurve,
+      mint: failedMint.publicKey,
+      platformConfig: platformConfig,
+      userDeposit: userBDeposit,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const depositAmount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+    await program.methods.depositAlpha(depositAmount, null).accounts(alphaAccounts).signers([userB]).rpc();
+
+    // Half comes out early and pays the penalty
+    const withdrawAmount = depositAmount.divn(2);
+    const penalty = withdrawAmount.muln(1000).divn(10000);
+    await program.methods.withdrawAlpha(withdrawAmount).accounts(alphaAccounts).signers([userB]).rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+
+    await program.methods
+      .finalizeAlpha()
+      .accounts({
+        bondingCurve: failedCurve,
+        mint: failedMint.publicKey,
+      })
+      .rpc();
+
+    const curve = await program.account.tokenBondingCurve.fetch(failedCurve);
+    assert.equal(curve.alphaFailed, 1, "Launch should be marked failed");
+    assert.equal(curve.tradingLive, 0, "Trading should never open");
+
+    const solBefore = await provider.connection.getBalance(userB.publicKey);
+
+    await program.methods
+      .refundAlpha()
+      .accounts({
+        user: userB.publicKey,
+        bondingCurve: failedCurve,
+        mint: failedMint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userBDeposit,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    const solAfter = await provider.connection.getBalance(userB.publicKey);
+    const depositState = await program.account.userAlphaDeposit.fetch(userBDeposit);
+    const curveAfter = await program.account.tokenBondingCurve.fetch(failedCurve);
+
+    assert.isTrue(depositState.refunded, "Deposit should be marked refunded");
+    // The provider pays the tx fee, so the balance moves by exactly the refund
+    assert.equal(
+      solAfter - solBefore,
+      depositAmount.sub(withdrawAmount).add(penalty).toNumber(),
+      "Refund should include the withdraw penalty"
+    );
+    assert.equal(curveAfter.realSolReserves.toNumber(), 0, "No penalty should be stranded in the curve");
+
+    console.log(" Failed Launch Refunded");
+    console.log("   Refunded:", solAfter - solBefore, "lamports, incl. penalty", penalty.toString());
+  });
+
+  it("16. Oversubscribed Alpha Refunds Excess Above Hard Cap", async () => {
+    const hardCap = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
+
+    const { mint: cappedMint, curve: cappedCurve, vault: cappedCurveVault } = await createTestToken({
+      symbol: "CAP",
+      alphaVault: { hardCap }, // Only 0.05 SOL goes through the curve
+    });
+    const userBDeposit = alphaDepositPda(userB.publicKey, cappedMint.publicKey);
+    const userBTokenAccount = tokenAccountFor(userB.publicKey, cappedMint.publicKey);
+
+    await program.methods
+      .depositAlpha(new anchor.BN(0.1 * LAMPORTS_PER_SOL), null)
+      .accounts({
+        user: userB.publicKey,
+        bondingCurve: cappedCurve,
+        mint: cappedMint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userBDeposit,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    await program.methods
+      .finalizeAlpha()
+      .accounts({
+        bondingCurve: cappedCurve,
+        mint: cappedMint.publicKey,
+      })
+      .rpc();
+
+    const curve = await program.account.tokenBondingCurve.fetch(cappedCurve);
+    assert.equal(curve.alphaSolUsed.toString(), hardCap.toString(), "Only the cap should be converted");
+    assert.equal(curve.realSolReserves.toString(), hardCap.toString(), "Reserves should hold only the cap");
+
+    await program.methods
+      .claimAlpha(false)
+      .accounts({
+        claimer: userB.publicKey,
+        bondingCurve: cappedCurve,
+        mint: cappedMint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userBDeposit,
+        bondingCurveTokenAccount: cappedCurveVault,
+        claimerTokenAccount: userBTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    const depositState = await program.account.userAlphaDeposit.fetch(userBDeposit);
+    assert.equal(
+      depositState.solRefunded.toString(),
+      new anchor.BN(0.05 * LAMPORTS_PER_SOL).toString(),
+      "Sole depositor should get back everything above the cap"
+    );
+    assert.equal(
+      depositState.tokensOwed.toString(),
+      curve.totalAlphaToken.toString(),
+      "Sole depositor should receive all alpha tokens"
+    );
+
+    console.log(" Oversubscribed Alpha Claimed");
+    console.log("   Tokens:", depositState.tokensOwed.toString());
+    console.log("   SOL Refunded:", depositState.solRefunded.toString(), "lamports");
+
+    // Fully claimed, so the deposit can be closed to reclaim rent
+    await program.methods
+      .closeAlphaDeposit()
+      .accounts({
+        user: userB.publicKey,
+        bondingCurve: cappedCurve,
+        mint: cappedMint.publicKey,
+        userDeposit: userBDeposit,
+      })
+      .signers([userB])
+      .rpc();
+
+    const closedDeposit = await program.account.userAlphaDeposit.fetchNullable(userBDeposit);
+    assert.isNull(closedDeposit, "Deposit account should be closed");
+    console.log("   Deposit account closed, rent returned");
+  });
+
+  it("17. Allowlisted Alpha Only Accepts Wallets In The Merkle Tree", async () => {
+    // Two-leaf tree: User B (capped at 0.2 SOL) and the creator (uncapped)
+    const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+    const leaf = (wallet: PublicKey, maxAmount: anchor.BN) =>
+      sha256(Buffer.from([0]), wallet.toBuffer(), maxAmount.toArrayLike(Buffer, "le", 8));
+    const node = (a: Buffer, b: Buffer) =>
+      Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+
+    const userBCap = new anchor.BN(0.2 * LAMPORTS_PER_SOL);
+    const userBLeaf = leaf(userB.publicKey, userBCap);
+    const creatorLeaf = leaf(creator.publicKey, new anchor.BN(0));
+    const root = node(userBLeaf, creatorLeaf);
+
+    // Schedule the alpha phase a few seconds out so the creator can still set the root
+    const chainNow = await provider.connection.getBlockTime(await provider.connection.getSlot());
+    const alphaStart = new anchor.BN(chainNow + 4);
+
+    const { mint: listedMint, curve: listedCurve } = await createTestToken({
+      symbol: "LIST",
+      alphaDurationSeconds: 60,
+      alphaVault: { startTime: alphaStart }, // Deposits open once the allowlist is set
+    });
+    const userADepositListed = alphaDepositPda(userA.publicKey, listedMint.publicKey);
+    const userBDepositListed = alphaDepositPda(userB.publicKey, listedMint.publicKey);
+
+    // Deposits are rejected until the scheduled start
+    try {
+      await program.methods
+        .depositAlpha(new anchor.BN(0.1 * LAMPORTS_PER_SOL), null)
+        .accounts({
+          user: userB.publicKey,
+          bondingCurve: listedCurve,
+          mint: listedMint.publicKey,
+          platformConfig: platformConfig,
+          userDeposit: userBDepositListed,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userB])
+        .rpc();
+      assert.fail("Deposit before the scheduled start should fail");
+    } catch (err) {
+      assert.include(err.toString(), "AlphaPhaseNotStarted");
+    }
+
+    await program.methods
+      .setAlphaMerkleRoot(Array.from(root))
+      .accounts({
+        creator: creator.publicKey,
+        bondingCurve: listedCurve,
+        mint: listedMint.publicKey,
+      })
+      .signers([creator])
+      .rpc();
+
+    // Wait for deposits to open
+    await new Promise((resolve) => setTimeout(resolve, 6000));
+
+    // User A is not in the tree
+    try {
+      await program.methods
+        .depositAlpha(new anchor.BN(0.1 * LAMPORTS_PER_SOL), null)
+        .accounts({
+          user: userA.publicKey,
+          bondingCurve: listedCurve,
+          mint: listedMint.publicKey,
+          platformConfig: platformConfig,
+          userDeposit: userADepositListed,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([userA])
+        .rpc();
+      assert.fail("Unlisted wallet should not be able to deposit");
+    } catch (err) {
+      assert.include(err.toString(), "NotAllowlisted");
+    }
+
+    // User B proves membership with the creator's leaf as sibling
+    const depositAmount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+    await program.methods
+      .depositAlpha(depositAmount, {
+        maxAmount: userBCap,
+        proof: [Array.from(creatorLeaf)],
+      })
+      .accounts({
+        user: userB.publicKey,
+        bondingCurve: listedCurve,
+        mint: listedMint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userBDepositListed,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    const depositState = await program.account.userAlphaDeposit.fetch(userBDepositListed);
+    assert.equal(depositState.solAmount.toString(), depositAmount.toString());
+
+    const listedAccounts = {
+      user: userB.publicKey,
+      bondingCurve: listedCurve,
+      mint: listedMint.publicKey,
+      platformConfig: platformConfig,
+      userDeposit: userBDepositListed,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // The leaf cap is cumulative: 0.1 + 0.15 SOL is over User B's 0.2 SOL
+    await expectError(
+      program.methods
+        .depositAlpha(new anchor.BN(0.15 * LAMPORTS_PER_SOL), {
+          maxAmount: userBCap,
+          proof: [Array.from(creatorLeaf)],
+        })
+        .accounts(listedAccounts)
+        .signers([userB])
+        .rpc(),
+      "DepositAboveMaximum"
+    );
+
+    // Claiming a bigger cap changes the leaf, so the proof no longer matches
+    await expectError(
+      program.methods
+        .depositAlpha(new anchor.BN(0.15 * LAMPORTS_PER_SOL), {
+          maxAmount: userBCap.muln(2),
+          proof: [Array.from(creatorLeaf)],
+        })
+        .accounts(listedAccounts)
+        .signers([userB])
+        .rpc(),
+      "NotAllowlisted"
+    );
+
+    console.log(" Allowlisted Alpha Deposit Accepted");
+    console.log("   Merkle Root:", root.toString("hex"));
+  });
+
+  it("18. Vested Alpha Claims Unlock Linearly", async () => {
+    const { mint: vestedMint, curve: vestedCurve, vault: vestedCurveVault } = await createTestToken({
+      symbol: "VEST",
+      alphaVault: { vestingDurationSeconds: new anchor.BN(3600) }, // Unlocks over an hour
+    });
+    const userBDeposit = alphaDepositPda(userB.publicKey, vestedMint.publicKey);
+    const userBTokenAccount = tokenAccountFor(userB.publicKey, vestedMint.publicKey);
+
+    await program.methods
+      .depositAlpha(new anchor.BN(0.1 * LAMPORTS_PER_SOL), null)
+      .accounts({
+        user: userB.publicKey,
+        bondingCurve: vestedCurve,
+        mint: vestedMint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userBDeposit,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    await program.methods
+      .finalizeAlpha()
+      .accounts({
+        bondingCurve: vestedCurve,
+        mint: vestedMint.publicKey,
+      })
+      .rpc();
+
+    // Let a couple of seconds vest
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    await program.methods
+      .claimAlpha(false)
+      .accounts({
+        claimer: userB.publicKey,
+        bondingCurve: vestedCurve,
+        mint: vestedMint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userBDeposit,
+        bondingCurveTokenAccount: vestedCurveVault,
+        claimerTokenAccount: userBTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    const depositState = await program.account.userAlphaDeposit.fetch(userBDeposit);
+    assert.ok(depositState.tokensClaimed.toNumber() > 0, "Some tokens should have vested");
+    assert.ok(
+      depositState.tokensClaimed.lt(depositState.tokensOwed),
+      "Most of the allocation should still be locked"
+    );
+    assert.isFalse(depositState.claimed, "Claim stays open until fully vested");
+
+    console.log(" Partial Vested Claim");
+    console.log("   Claimed:", depositState.tokensClaimed.toString(), "/", depositState.tokensOwed.toString());
+
+    // Anyone can crank newly vested tokens out to depositors
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    await program.methods
+      .distributeAlpha()
+      .accounts({
+        payer: admin.publicKey,
+        bondingCurve: vestedCurve,
+        mint: vestedMint.publicKey,
+        platformConfig: platformConfig,
+        bondingCurveTokenAccount: vestedCurveVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: userBDeposit, isWritable: true, isSigner: false },
+        { pubkey: userB.publicKey, isWritable: true, isSigner: false },
+        { pubkey: userBTokenAccount, isWritable: true, isSigner: false },
+      ])
+      .rpc();
+
+    const depositAfterCrank = await program.account.userAlphaDeposit.fetch(userBDeposit);
+    assert.ok(
+      depositAfterCrank.tokensClaimed.gt(depositState.tokensClaimed),
+      "Crank should pay out newly vested tokens"
+    );
+    console.log("   After crank:", depositAfterCrank.tokensClaimed.toString(), "/", depositAfterCrank.tokensOwed.toString());
+  });
+
+  it("19. Creator Fee Above Platform Maximum Is Rejected", async () => {
+    const config = await program.account.platformConfig.fetch(platformConfig);
+
+    try {
+      await createTestToken({ symbol: "GREED", creatorFeeBps: config.maxCreatorFeeBps + 1 });
+      assert.fail("Creator fee above the platform maximum should fail");
+    } catch (err) {
+      assert.include(err.toString(), "CreatorFeeTooHigh");
+    }
+
+    console.log(" Creator Fee Capped At", config.maxCreatorFeeBps, "bps");
+  });
+
+  it("20. Creator And Platform Claim Accrued Trading Fees", async () => {
+    const curveBefore = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    const creatorFees = curveBefore.accruedCreatorFees;
+    const platformFees = curveBefore.accruedPlatformFees;
+
+    assert.ok(creatorFees.toNumber() > 0, "Trades should have accrued creator fees");
+    assert.ok(platformFees.toNumber() > 0, "Trades should have accrued platform fees");
+
+    const creatorBefore = await provider.connection.getBalance(creator.publicKey);
+
+    // Pausing the token stops trading but not withdrawal of earned fees
+    const pauseAccounts = {
+      platformConfig: platformConfig,
+      bondingCurve: bondingCurve,
+      mint: mintKeypair.publicKey,
+      authority: admin.publicKey,
+    };
+    await program.methods.setTokenPaused(true).accounts(pauseAccounts).rpc();
+
+    await program.methods
+      .claimCreatorFees()
+      .accounts({
+        creator: creator.publicKey,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+      })
+      .signers([creator])
+      .rpc();
+
+    const creatorAfter = await provider.connection.getBalance(creator.publicKey);
+    assert.ok(creatorAfter > creatorBefore, "Creator should receive fees");
+
+    const feeWalletBefore = await provider.connection.getBalance(feeWallet.publicKey);
+
+    await program.methods
+      .claimPlatformFees()
+      .accounts({
+        platformConfig: platformConfig,
+        platformFeeWallet: feeWallet.publicKey,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    const feeWalletAfter = await provider.connection.getBalance(feeWallet.publicKey);
+    assert.equal(feeWalletAfter - feeWalletBefore, platformFees.toNumber(), "Fee wallet should receive exactly the accrued fees");
+
+    await program.methods.setTokenPaused(false).accounts(pauseAccounts).rpc();
+
+    const curveAfter = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    assert.equal(curveAfter.accruedCreatorFees.toNumber(), 0);
+    assert.equal(curveAfter.accruedPlatformFees.toNumber(), 0);
+
+    console.log(" Accrued Fees Claimed");
+    console.log("   Creator:", creatorFees.toString(), "lamports");
+    console.log("   Platform:", platformFees.toString(), "lamports");
+  });
+
+  it("21. Referrer Earns A Share Of The Platform Fee", async () => {
+    const [referrer] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referrer"), userA.publicKey.toBuffer()],
+      program.programId
+    );
+    const [userBTokenAccount] = PublicKey.findProgramAddressSync(
+      [userB.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mintKeypair.publicKey.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+
+    await program.methods
+      .registerReferrer()
+      .accounts({
+        wallet: userA.publicKey,
+        referrer: referrer,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userA])
+      .rpc();
+
+    // Referrers get half of the platform fee
+    await program.methods
+      .updatePlatformConfig({
+        platformFeeWallet: null,
+        platformFeeBps: null,
+        maxCreatorFeeBps: null,
+        referralFeeBps: 5000,
+        maxSniperFeeBps: null,
+        maxSniperFeeDurationSeconds: null,
+        platformTokenCreationFee: null,
+        completionSolThreshold: null,
+        completionTokenThreshold: null,
+      })
+      .accounts({
+        platformConfig: platformConfig,
+        authority: admin.publicKey,
+      })
+      .rpc();
+
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    const buyAmount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+    const platformFee = buyAmount.muln(config.platformFeeBps).divn(10000);
+    const expectedReferralFee = platformFee.muln(5000).divn(10000);
+
+    const curveBefore = await program.account.tokenBondingCurve.fetch(bondingCurve);
+
+    await program.methods
+      .buyTokens(buyAmount, new anchor.BN(0))
+      .accounts({
+        buyer: userB.publicKey,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        platformConfig: platformConfig,
+        bondingCurveTokenAccount: bondingCurveVault,
+        buyerTokenAccount: userBTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        referrer: referrer,
+        feeSchedule: null,
+        traderVolume: traderVolumePda(userB.publicKey),
+      })
+      .signers([userB])
+      .rpc();
+
+    const referrerState = await program.account.referrer.fetch(referrer);
+    const curveAfter = await program.account.tokenBondingCurve.fetch(bondingCurve);
+
+    assert.equal(referrerState.accruedFees.toString(), expectedReferralFee.toString());
+    assert.equal(
+      referrerState.totalVolume.toString(),
+      buyAmount.toString(),
+      "Referred volume is the trade size before fees"
+    );
+    assert.equal(
+      curveAfter.accruedPlatformFees.sub(curveBefore.accruedPlatformFees).toString(),
+      platformFee.sub(expectedReferralFee).toString(),
+      "Platform keeps the rest of its fee"
+    );
+
+    await program.methods
+      .claimReferralFees()
+      .accounts({
+        wallet: userA.publicKey,
+        referrer: referrer,
+      })
+      .signers([userA])
+      .rpc();
+
+    const referrerAfterClaim = await program.account.referrer.fetch(referrer);
+    assert.equal(referrerAfterClaim.accruedFees.toNumber(), 0);
+    assert.equal(referrerAfterClaim.totalEarned.toString(), expectedReferralFee.toString());
+
+    console.log(" Referral Fee Paid");
+    console.log("   Referral fee:", expectedReferralFee.toString(), "lamports");
+    console.log("   Referred volume:", referrerState.totalVolume.toString(), "lamports");
+  });
+
+  it("22. Anti-Sniper Fee Decays After Launch", async () => {
+    // 20% extra buy fee, decaying to 0 over a minute, paid into the curve
+    const { mint: sniperMint, curve: sniperCurve, vault: sniperCurveVault } = await createTestToken({
+      symbol: "SNIPE",
+      sniperFee: { feeBps: 2000, durationSeconds: new anchor.BN(60), destination: { curve: {} } },
+    });
+    const userATokenAccount = tokenAccountFor(userA.publicKey, sniperMint.publicKey);
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    const curveBefore = await program.account.tokenBondingCurve.fetch(sniperCurve);
+
+    // First buy finalizes the alpha phase and opens trading
+    await program.methods
+      .buyTokens(new anchor.BN(0.1 * LAMPORTS_PER_SOL), new anchor.BN(0))
+      .accounts({
+        buyer: userA.publicKey,
+        bondingCurve: sniperCurve,
+        mint: sniperMint.publicKey,
+        platformConfig: platformConfig,
+        bondingCurveTokenAccount: sniperCurveVault,
+        buyerTokenAccount: userATokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        referrer: null,
+        feeSchedule: null,
+        traderVolume: traderVolumePda(userA.publicKey),
+      })
+      .signers([userA])
+      .rpc();
+
+    const curveAfter = await program.account.tokenBondingCurve.fetch(sniperCurve);
+
+    // SOL used to buy moves both reserves equally; the sniper fee only adds to real
+    const sniperFee = curveAfter.realSolReserves
+      .sub(curveBefore.realSolReserves)
+      .sub(curveAfter.virtualSolReserves.sub(curveBefore.virtualSolReserves));
+
+    assert.equal(curveAfter.tradingLive, 1, "Trading should be live");
+    assert.equal(curveAfter.sniperFeeBps, 2000);
+    assert.ok(sniperFee.gtn(0), "Sniper fee should be added to curve reserves");
+    assert.ok(
+      sniperFee.lten(0.1 * LAMPORTS_PER_SOL * 0.2),
+      "Sniper fee should not exceed its starting rate"
+    );
+
+    // Same buy later in the window pays a smaller fee
+    await new Promise((resolve) => setTimeout(resolve, 10000));
+
+    await program.methods
+      .buyTokens(new anchor.BN(0.1 * LAMPORTS_PER_SOL), new anchor.BN(0))
+      .accounts(buyAccounts(userA.publicKey, { mint: sniperMint, curve: sniperCurve, vault: sniperCurveVault }))
+      .signers([userA])
+      .rpc();
+
+    const curveLater = await program.account.tokenBondingCurve.fetch(sniperCurve);
+    const laterSniperFee = curveLater.realSolReserves
+      .sub(curveAfter.realSolReserves)
+      .sub(curveLater.virtualSolReserves.sub(curveAfter.virtualSolReserves));
+
+    assert.ok(laterSniperFee.gtn(0), "Fee should still apply inside the window");
+    assert.ok(laterSniperFee.lt(sniperFee), "Fee should decay over the window");
+
+    console.log(" Anti-Sniper Fee Charged");
+    console.log("   Sniper fee:", sniperFee.toString(), "lamports");
+    console.log("   Sniper fee 10s later:", laterSniperFee.toString(), "lamports");
+  });
+
+  it("23. High-Volume Traders Get A Lower Platform Fee", async () => {
+    const [feeSchedule] = PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_schedule")],
+      program.programId
+    );
+    const [userBTokenAccount] = PublicKey.findProgramAddressSync(
+      [userB.publicKey.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mintKeypair.publicKey.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const userBTraderVolume = traderVolumePda(userB.publicKey);
+
+    const scheduleAccounts = {
+      platformConfig: platformConfig,
+      feeSchedule: feeSchedule,
+      authority: admin.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // Tiers can only get cheaper as volume grows
+    await expectError(
+      program.methods
+        .setFeeSchedule([
+          { minVolume: new anchor.BN(1), feeBps: 50 },
+          { minVolume: new anchor.BN(LAMPORTS_PER_SOL), feeBps: 100 },
+        ])
+        .accounts(scheduleAccounts)
+        .rpc(),
+      "InvalidFeeSchedule"
+    );
+
+    // Anyone who has traded at all drops to 0.5%
+    await program.methods
+      .setFeeSchedule([{ minVolume: new anchor.BN(1), feeBps: 50 }])
+      .accounts(scheduleAccounts)
+      .rpc();
+
+    const volumeBefore = await program.account.traderVolume.fetch(userBTraderVolume);
+    const curveBefore = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    assert.ok(volumeBefore.totalVolume.gtn(0), "Earlier trades should be recorded");
+
+    const buyAmount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+
+    await program.methods
+      .buyTokens(buyAmount, new anchor.BN(0))
+      .accounts({
+        buyer: userB.publicKey,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        platformConfig: platformConfig,
+        bondingCurveTokenAccount: bondingCurveVault,
+        buyerTokenAccount: userBTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        referrer: null,
+        feeSchedule: feeSchedule,
+        traderVolume: userBTraderVolume,
+      })
+      .signers([userB])
+      .rpc();
+
+    const volumeAfter = await program.account.traderVolume.fetch(userBTraderVolume);
+    const curveAfter = await program.account.tokenBondingCurve.fetch(bondingCurve);
+
+    assert.equal(
+      curveAfter.accruedPlatformFees.sub(curveBefore.accruedPlatformFees).toString(),
+      buyAmount.muln(50).divn(10000).toString(),
+      "Tiered fee should replace the flat platform fee"
+    );
+    assert.equal(
+      volumeAfter.totalVolume.sub(volumeBefore.totalVolume).toString(),
+      buyAmount.toString(),
+      "Buy volume is the trade size before fees"
+    );
+
+    // Sells use the same tier
+    const tokenBalance = await provider.connection.getTokenAccountBalance(userBTokenAccount);
+    await program.methods
+      .sellTokens(new anchor.BN(tokenBalance.value.amount).divn(4), new anchor.BN(0))
+      .accounts({
+        seller: userB.publicKey,
+        bondingCurve: bondingCurve,
+        mint: mintKeypair.publicKey,
+        platformConfig: platformConfig,
+        bondingCurveTokenAccount: bondingCurveVault,
+        sellerTokenAccount: userBTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        referrer: null,
+        feeSchedule: feeSchedule,
+        traderVolume: userBTraderVolume,
+      })
+      .signers([userB])
+      .rpc();
+
+    const volumeAfterSell = await program.account.traderVolume.fetch(userBTraderVolume);
+    const curveAfterSell = await program.account.tokenBondingCurve.fetch(bondingCurve);
+
+    // Sell volume is sol_out_gross, which is also the virtual SOL drop
+    const sellVolume = volumeAfterSell.totalVolume.sub(volumeAfter.totalVolume);
+    assert.equal(
+      sellVolume.toString(),
+      curveAfter.virtualSolReserves.sub(curveAfterSell.virtualSolReserves).toString(),
+      "Sell volume is the trade size before fees"
+    );
+    assert.equal(
+      curveAfterSell.accruedPlatformFees.sub(curveAfter.accruedPlatformFees).toString(),
+      sellVolume.muln(50).divn(10000).toString(),
+      "Tiered fee should apply to sells too"
+    );
+
+    // Traders can skip the volume account: no PDA rent, and no tier either
+    const creatorTraderVolume = traderVolumePda(creator.publicKey);
+    const curveBeforeUntracked = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    const config = await program.account.platformConfig.fetch(platformConfig);
+
+    await program.methods
+      .buyTokens(buyAmount, new anchor.BN(0))
+      .accounts({
+        ...buyAccounts(creator.publicKey, { mint: mintKeypair, curve: bondingCurve, vault: bondingCurveVault }),
+        feeSchedule: feeSchedule,
+        traderVolume: null,
+      })
+      .signers([creator])
+      .rpc();
+
+    const curveAfterUntracked = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    assert.isNull(
+      await program.account.traderVolume.fetchNullable(creatorTraderVolume),
+      "No volume account should be created"
+    );
+    assert.equal(
+      curveAfterUntracked.accruedPlatformFees.sub(curveBeforeUntracked.accruedPlatformFees).toString(),
+      buyAmount.muln(config.platformFeeBps).divn(10000).toString(),
+      "Untracked trades pay the flat fee"
+    );
+
+    // Back to the flat fee for everyone
+    await program.methods
+      .setFeeSchedule([])
+      .accounts(scheduleAccounts)
+      .rpc();
+
+    console.log(" Volume Tier Applied");
+    console.log("   Trader volume:", volumeAfter.totalVolume.toString(), "lamports");
+  });
+
+  it("24. Curve-Bound Sniper Fee Completes The Curve When It Crosses The Threshold", async () => {
+    const config = await program.account.platformConfig.fetch(platformConfig);
+
+    // Tokens copy the threshold at creation, so lower it just for this launch
+    await updatePlatformConfig({ completionSolThreshold: new anchor.BN(0.3 * LAMPORTS_PER_SOL) });
+    const token = await createTestToken({
+      symbol: "EDGE",
+      sniperFee: {
+        feeBps: config.maxSniperFeeBps,
+        durationSeconds: new anchor.BN(60),
+        destination: { curve: {} },
+      },
+    });
+    await updatePlatformConfig({ completionSolThreshold: config.completionSolThreshold });
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    // At max sniper rate, 0.4 SOL puts well under 0.3 SOL into the buy itself,
+    // but buy + sniper fee lands above the threshold
+    await program.methods
+      .buyTokens(new anchor.BN(0.4 * LAMPORTS_PER_SOL), new anchor.BN(0))
+      .accounts(buyAccounts(userA.publicKey, token))
+      .signers([userA])
+      .rpc();
+
+    const curve = await program.account.tokenBondingCurve.fetch(token.curve);
+    assert.ok(curve.realSolReserves.gte(curve.completionSolThreshold), "Fee should carry reserves past the threshold");
+    assert.equal(curve.isComplete, 1, "Crossing the threshold should complete the curve");
+
+    await expectError(
+      program.methods
+        .buyTokens(new anchor.BN(0.01 * LAMPORTS_PER_SOL), new anchor.BN(0))
+        .accounts(buyAccounts(userA.publicKey, token))
+        .signers([userA])
+        .rpc(),
+      "SaleComplete"
+    );
+
+    console.log(" Sniper Fee Completed The Curve");
+    console.log("   Real SOL Reserves:", curve.realSolReserves.toString());
+  });
+
+  it("25. Fully Withdrawn Alpha Deposit Can Be Closed After The Window", async () => {
+    const token = await createTestToken({ symbol: "GONE", alphaDurationSeconds: 4 });
+    const userBDeposit = alphaDepositPda(userB.publicKey, token.mint.publicKey);
+    const depositAmount = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
+
+    const alphaAccounts = {
+      user: userB.publicKey,
+      bondingCurve: token.curve,
+      mint: token.mint.publicKey,
+      platformConfig: platformConfig,
+      userDeposit: userBDeposit,
+      systemProgram: SystemProgram.programId,
+    };
+
+    await program.methods.depositAlpha(depositAmount, null).accounts(alphaAccounts).signers([userB]).rpc();
+    await program.methods.withdrawAlpha(depositAmount).accounts(alphaAccounts).signers([userB]).rpc();
+
+    const closeAccounts = {
+      user: userB.publicKey,
+      bondingCurve: token.curve,
+      mint: token.mint.publicKey,
+      userDeposit: userBDeposit,
+    };
+
+    // The window is still open, so the PDA could be re-created
+    await expectError(
+      program.methods.closeAlphaDeposit().accounts(closeAccounts).signers([userB]).rpc(),
+      "AlphaPhaseNotEnded"
+    );
+
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+
+    await program.methods.closeAlphaDeposit().accounts(closeAccounts).signers([userB]).rpc();
+
+    const closedDeposit = await program.account.userAlphaDeposit.fetchNullable(userBDeposit);
+    assert.isNull(closedDeposit, "Withdrawn deposit should be closed");
+    console.log(" Withdrawn Deposit Closed, Rent Returned");
+  });
+
+  it("26. Anti-Sniper Fee Respects Platform Bounds And Destinations", async () => {
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    const sniperFee = (feeBps: number, durationSeconds: anchor.BN, destination: CreateTokenArgs[6]["destination"]) => ({
+      feeBps,
+      durationSeconds,
+      destination,
+    });
+
+    await expectError(
+      createTestToken({
+        symbol: "HIGH",
+        sniperFee: sniperFee(config.maxSniperFeeBps + 1, new anchor.BN(60), { curve: {} }),
+      }),
+      "SniperFeeTooHigh"
+    );
+    await expectError(
+      createTestToken({
+        symbol: "NEG",
+        sniperFee: sniperFee(1000, new anchor.BN(-1), { curve: {} }),
+      }),
+      "InvalidSniperFeeDuration"
+    );
+    await expectError(
+      createTestToken({
+        symbol: "LONG",
+        sniperFee: sniperFee(1000, config.maxSniperFeeDurationSeconds.addn(1), { curve: {} }),
+      }),
+      "InvalidSniperFeeDuration"
+    );
+
+    const creatorToken = await createTestToken({
+      symbol: "SNCR",
+      sniperFee: sniperFee(2000, new anchor.BN(60), { creator: {} }),
+    });
+    const platformToken = await createTestToken({
+      symbol: "SNPL",
+      sniperFee: sniperFee(2000, new anchor.BN(60), { platform: {} }),
+    });
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    const buyAmount = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+    const buy = async (token: TestToken) => {
+      const before = await program.account.tokenBondingCurve.fetch(token.curve);
+      await program.methods
+        .buyTokens(buyAmount, new anchor.BN(0))
+        .accounts(buyAccounts(userA.publicKey, token))
+        .signers([userA])
+        .rpc();
+      const after = await program.account.tokenBondingCurve.fetch(token.curve);
+
+      // Nothing beyond the buy itself lands in the reserves
+      assert.equal(
+        after.realSolReserves.sub(before.realSolReserves).toString(),
+        after.virtualSolReserves.sub(before.virtualSolReserves).toString(),
+        "Sniper fee should not go to the curve"
+      );
+      return { before, after };
+    };
+
+    // No creator fee on this token, so every accrued lamport is sniper fee
+    const creatorBuy = await buy(creatorToken);
+    assert.ok(
+      creatorBuy.after.accruedCreatorFees.sub(creatorBuy.before.accruedCreatorFees).gtn(0),
+      "Sniper fee should accrue to the creator"
+    );
+
+    const platformBuy = await buy(platformToken);
+    const flatPlatformFee = buyAmount.muln(config.platformFeeBps).divn(10000);
+    assert.ok(
+      platformBuy.after.accruedPlatformFees.sub(platformBuy.before.accruedPlatformFees).gt(flatPlatformFee),
+      "Sniper fee should accrue to the platform on top of its flat fee"
+    );
+    assert.ok(
+      platformBuy.after.accruedCreatorFees.eq(platformBuy.before.accruedCreatorFees),
+      "Creator should get nothing from a platform-bound sniper fee"
+    );
+
+    console.log(" Sniper Fee Bounds And Destinations Enforced");
+  });
+
+  it("27. Crossing Buy Is Capped At The Threshold And Completes The Curve", async () => {
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    const threshold = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
+
+    await updatePlatformConfig({ completionSolThreshold: threshold });
+    const token = await createTestToken({ symbol: "CAP" });
+    await updatePlatformConfig({ completionSolThreshold: config.completionSolThreshold });
+
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    const buyAmount = new anchor.BN(0.2 * LAMPORTS_PER_SOL);
+    const curveBalanceBefore = await provider.connection.getBalance(token.curve);
+
+    await program.methods
+      .buyTokens(buyAmount, new anchor.BN(0))
+      .accounts(buyAccounts(userB.publicKey, token))
+      .signers([userB])
+      .rpc();
+
+    const curve = await program.account.tokenBondingCurve.fetch(token.curve);
+    const charged = (await provider.connection.getBalance(token.curve)) - curveBalanceBefore;
+
+    // Only the SOL that fits under the threshold (plus its fees) is taken
+    assert.ok(curve.realSolReserves.gte(threshold), "Reserves should reach the threshold");
+    assert.ok(
+      curve.realSolReserves.lt(threshold.muln(101).divn(100)),
+      "Reserves should not run past the threshold"
+    );
+    assert.equal(
+      charged,
+      curve.realSolReserves.add(curve.accruedPlatformFees).add(curve.accruedCreatorFees).toNumber(),
+      "Curve should hold exactly the filled SOL and its fees"
+    );
+    assert.ok(charged < buyAmount.toNumber() / 2, "Unfilled lamports should stay with the buyer");
+    assert.equal(curve.isComplete, 1, "Crossing buy should complete the curve");
+
+    const tokenBalance = await provider.connection.getTokenAccountBalance(
+      tokenAccountFor(userB.publicKey, token.mint.publicKey)
+    );
+    assert.ok(new anchor.BN(tokenBalance.value.amount).gtn(0), "Buyer should get the capped fill");
+
+    await expectError(
+      program.methods
+        .buyTokens(new anchor.BN(0.01 * LAMPORTS_PER_SOL), new anchor.BN(0))
+        .accounts(buyAccounts(userB.publicKey, token))
+        .signers([userB])
+        .rpc(),
+      "SaleComplete"
+    );
+    await expectError(
+      program.methods
+        .sellTokens(new anchor.BN(tokenBalance.value.amount), new anchor.BN(0))
+        .accounts(sellAccounts(userB.publicKey, token))
+        .signers([userB])
+        .rpc(),
+      "SaleComplete"
+    );
+
+    console.log(" Crossing Buy Capped At Completion");
+    console.log("   Charged:", charged, "of", buyAmount.toString(), "lamports");
+  });
+
+  it("28. Completed Curve Migrates To A Pool That Trades And Takes Liquidity", async () => {
+    // The main token is still trading on its curve
+    await expectError(
+      migrate({ mint: mintKeypair, curve: bondingCurve, vault: bondingCurveVault }),
+      "CurveNotComplete"
+    );
+
+    const token = await createCompletedToken("POOL");
+
+    const curveBefore = await program.account.tokenBondingCurve.fetch(token.curve);
+    assert.equal(curveBefore.isComplete, 1);
+
+    await migrate(token);
+
+    const { pool, lpMint, poolTokenAccount } = poolAccountsFor(token);
+    const curveAfter = await program.account.tokenBondingCurve.fetch(token.curve);
+    let poolState = await program.account.liquidityPool.fetch(pool);
+
+    assert.equal(curveAfter.isMigrated, 1);
+    assert.equal(curveAfter.realSolReserves.toNumber(), 0);
+    assert.equal(curveAfter.realTokenReserves.toNumber(), 0);
+    assert.equal(poolState.solReserves.toString(), curveBefore.realSolReserves.toString());
+    assert.equal(poolState.tokenReserves.toString(), curveBefore.realTokenReserves.toString());
+
+    // Locked LP is floor(sqrt(sol * tokens))
+    const product = poolState.solReserves.mul(poolState.tokenReserves);
+    const locked = poolState.lockedLpSupply;
+    assert.ok(locked.mul(locked).lte(product) && locked.addn(1).mul(locked.addn(1)).gt(product));
+
+    await expectError(migrate(token), "AlreadyMigrated");
+
+    const userATokenAccount = tokenAccountFor(userA.publicKey, token.mint.publicKey);
+    const userALpAccount = tokenAccountFor(userA.publicKey, lpMint);
+    const swapAccounts = {
+      user: userA.publicKey,
+      pool,
+      mint: token.mint.publicKey,
+      poolTokenAccount,
+      userTokenAccount: userATokenAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+    const expectedSwapOut = (amountIn: anchor.BN, reserveIn: anchor.BN, reserveOut: anchor.BN) => {
+      const afterFee = amountIn.muln(10000 - poolState.feeBps).divn(10000);
+      return reserveOut.mul(afterFee).div(reserveIn.add(afterFee));
+    };
+    const tokenBalance = async () =>
+      new anchor.BN((await provider.connection.getTokenAccountBalance(userATokenAccount)).value.amount);
+
+    // Buy on the pool: SOL in, tokens out
+    const solIn = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
+    const tokensExpected = expectedSwapOut(solIn, poolState.solReserves, poolState.tokenReserves);
+
+    await expectError(
+      program.methods
+        .poolSwap(solIn, tokensExpected.addn(1), true)
+        .accounts(swapAccounts)
+        .signers([userA])
+        .rpc(),
+      "SlippageExceeded"
+    );
+
+    const tokensBeforeSwap = await tokenBalance();
+    await program.methods.poolSwap(solIn, tokensExpected, true).accounts(swapAccounts).signers([userA]).rpc();
+    assert.equal((await tokenBalance()).sub(tokensBeforeSwap).toString(), tokensExpected.toString());
+
+    poolState = await program.account.liquidityPool.fetch(pool);
+
+    // Sell on the pool: tokens in, SOL out
+    const solExpected = expectedSwapOut(tokensExpected, poolState.tokenReserves, poolState.solReserves);
+
+    await expectError(
+      program.methods
+        .poolSwap(tokensExpected, solExpected.addn(1), false)
+        .accounts(swapAccounts)
+        .signers([userA])
+        .rpc(),
+      "SlippageExceeded"
+    );
+
+    const solBeforeSell = await provider.connection.getBalance(userA.publicKey);
+    await program.methods.poolSwap(tokensExpected, solExpected, false).accounts(swapAccounts).signers([userA]).rpc();
+    assert.equal((await provider.connection.getBalance(userA.publicKey)) - solBeforeSell, solExpected.toNumber());
+    assert.ok(solExpected.lt(solIn), "Round trip through the pool should cost the swap fee");
+
+    // Add then remove liquidity
+    poolState = await program.account.liquidityPool.fetch(pool);
+    const liquidityAccounts = {
+      provider: userA.publicKey,
+      pool,
+      mint: token.mint.publicKey,
+      lpMint,
+      poolTokenAccount,
+      providerTokenAccount: userATokenAccount,
+      providerLpAccount: userALpAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+    const solAdded = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
+    const tokensBeforeAdd = await tokenBalance();
+
+    await program.methods
+      .addLiquidity(solAdded, tokensBeforeAdd, new anchor.BN(1))
+      .accounts(liquidityAccounts)
+      .signers([userA])
+      .rpc();
+
+    const tokensAdded = tokensBeforeAdd.sub(await tokenBalance());
+    const lpMinted = new anchor.BN((await provider.connection.getTokenAccountBalance(userALpAccount)).value.amount);
+    assert.equal(
+      lpMinted.toString(),
+      solAdded.mul(poolState.lockedLpSupply).div(poolState.solReserves).toString(),
+      "LP should be minted pro rata to the SOL added"
+    );
+
+    const solBeforeRemove = await provider.connection.getBalance(userA.publicKey);
+    await program.methods
+      .removeLiquidity(lpMinted, new anchor.BN(0), new anchor.BN(0))
+      .accounts(liquidityAccounts)
+      .signers([userA])
+      .rpc();
+
+    const solRemoved = (await provider.connection.getBalance(userA.publicKey)) - solBeforeRemove;
+    const tokensRemoved = (await tokenBalance()).sub(tokensBeforeAdd.sub(tokensAdded));
+
+    // Rounding always favors the pool, by at most a couple of base units
+    assert.ok(solRemoved <= solAdded.toNumber() && solRemoved >= solAdded.toNumber() - 2);
+    assert.ok(tokensRemoved.lte(tokensAdded) && tokensRemoved.gte(tokensAdded.subn(2)));
+
+    // Only locked migration shares remain, and no one holds them
+    const lpSupply = await provider.connection.getTokenSupply(lpMint);
+    assert.equal(lpSupply.value.amount, "0");
+    await expectError(
+      program.methods
+        .removeLiquidity(new anchor.BN(1), new anchor.BN(0), new anchor.BN(0))
+        .accounts(liquidityAccounts)
+        .signers([userA])
+        .rpc(),
+      "0x1" // SPL Token: insufficient funds
+    );
+
+    poolState = await program.account.liquidityPool.fetch(pool);
+    assert.equal(poolState.lockedLpSupply.toString(), locked.toString(), "Locked LP should never move");
+    assert.ok(poolState.solReserves.gte(curveBefore.realSolReserves), "Migrated liquidity should stay in the pool");
+
+    console.log(" Pool Migration, Swaps And Liquidity Round Trip Work");
+    console.log("   Locked LP:", locked.toString());
+  });
+
+  it("29. Per-Wallet Deposit Bounds Are Enforced", async () => {
+    const sol = (amount: number) => new anchor.BN(amount * LAMPORTS_PER_SOL);
+    const token = await createTestToken({
+      symbol: "BNDS",
+      alphaDurationSeconds: 30,
+      alphaVault: { minDeposit: sol(0.02), maxDeposit: sol(0.05) },
+    });
+    const alphaAccounts = {
+      user: userB.publicKey,
+      bondingCurve: token.curve,
+      mint: token.mint.publicKey,
+      platformConfig: platformConfig,
+      userDeposit: alphaDepositPda(userB.publicKey, token.mint.publicKey),
+      systemProgram: SystemProgram.programId,
+    };
+    const deposit = (amount: anchor.BN) =>
+      program.methods.depositAlpha(amount, null).accounts(alphaAccounts).signers([userB]).rpc();
+    const withdraw = (amount: anchor.BN) =>
+      program.methods.withdrawAlpha(amount).accounts(alphaAccounts).signers([userB]).rpc();
+
+    await expectError(deposit(sol(0.01)), "DepositBelowMinimum");
+    await expectError(deposit(sol(0.06)), "DepositAboveMaximum");
+
+    await deposit(sol(0.03));
+
+    // The maximum is cumulative per wallet
+    await expectError(deposit(sol(0.03)), "DepositAboveMaximum");
+
+    // A partial exit can't leave less than the minimum behind
+    await expectError(withdraw(sol(0.02)), "DepositBelowMinimum");
+
+    // A full exit is always allowed
+    await withdraw(sol(0.03));
+
+    const userDeposit = await program.account.userAlphaDeposit.fetch(alphaAccounts.userDeposit);
+    assert.equal(userDeposit.solAmount.toNumber(), 0);
+
+    console.log(" Deposit Minimum And Maximum Enforced");
+  });
+
+  it("30. Finalize Crank Opens Trading So Depositors Can Claim Without A Buy", async () => {
+    const token = await createTestToken({ symbol: "CRNK", alphaDurationSeconds: 4 });
+    const userADepositPda = alphaDepositPda(userA.publicKey, token.mint.publicKey);
+    const depositAmount = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
+
+    await program.methods
+      .depositAlpha(depositAmount, null)
+      .accounts({
+        user: userA.publicKey,
+        bondingCurve: token.curve,
+        mint: token.mint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userADepositPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userA])
+      .rpc();
+
+    const finalize = () =>
+      program.methods
+        .finalizeAlpha()
+        .accounts({ bondingCurve: token.curve, mint: token.mint.publicKey })
+        .rpc();
+
+    await expectError(finalize(), "AlphaPhaseNotEnded");
+
+    await new Promise((resolve) => setTimeout(resolve, 5000));
+
+    // Anyone can crank; here the provider wallet does, with no trade on the curve
+    await finalize();
+
+    const curve = await program.account.tokenBondingCurve.fetch(token.curve);
+    assert.equal(curve.tradingLive, 1, "Crank should open trading");
+    assert.ok(curve.tradingLiveAt.gtn(0));
+    assert.equal(curve.alphaSolUsed.toString(), depositAmount.toString());
+    assert.ok(curve.totalAlphaToken.gtn(0), "Alpha deposits should be converted");
+
+    const userATokenAccount = tokenAccountFor(userA.publicKey, token.mint.publicKey);
+    await program.methods
+      .claimAlpha(false)
+      .accounts({
+        claimer: userA.publicKey,
+        bondingCurve: token.curve,
+        mint: token.mint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: userADepositPda,
+        bondingCurveTokenAccount: token.vault,
+        claimerTokenAccount: userATokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userA])
+      .rpc();
+
+    // Sole depositor gets the whole alpha allocation
+    const balance = await provider.connection.getTokenAccountBalance(userATokenAccount);
+    assert.equal(balance.value.amount, curve.totalAlphaToken.toString());
+
+    console.log(" Crank Finalized, Alpha Claimed With No Prior Buy");
+    console.log("   Claimed:", balance.value.amount);
+  });
+
+  it("31. Trades And Migration Emit Decodable CPI Events", async () => {
+    const config = await program.account.platformConfig.fetch(platformConfig);
+    const mainToken = { mint: mintKeypair, curve: bondingCurve, vault: bondingCurveVault };
+    const userATokenAccount = tokenAccountFor(userA.publicKey, mintKeypair.publicKey);
+    const buyAmount = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
+    const tokensBefore = await provider.connection.getTokenAccountBalance(userATokenAccount);
+
+    const signature = await program.methods
+      .buyTokens(buyAmount, new anchor.BN(0))
+      .accounts(buyAccounts(userA.publicKey, mainToken))
+      .signers([userA])
+      .rpc({ commitment: "confirmed" });
+
+    const trade = findEvent(await cpiEvents(signature), "Trade");
+    assert.ok(trade, "Buy should emit a Trade event");
+
+    const curve = await program.account.tokenBondingCurve.fetch(bondingCurve);
+    const tokensAfter = await provider.connection.getTokenAccountBalance(userATokenAccount);
+
+    assert.ok(trade.data.mint.equals(mintKeypair.publicKey));
+    assert.ok(trade.data.trader.equals(userA.publicKey));
+    assert.isTrue(trade.data.isBuy);
+    assert.equal(
+      trade.data.tokenAmount.toString(),
+      new anchor.BN(tokensAfter.value.amount).sub(new anchor.BN(tokensBefore.value.amount)).toString()
+    );
+    assert.equal(
+      trade.data.platformFee.toString(),
+      buyAmount.muln(config.platformFeeBps).divn(10000).toString()
+    );
+    assert.equal(trade.data.virtualSolReserves.toString(), curve.virtualSolReserves.toString());
+    assert.equal(trade.data.realTokenReserves.toString(), curve.realTokenReserves.toString());
+    assert.isFalse(trade.data.isComplete);
+
+    // Graduation: the event alone shows the curve emptied into the pool
+    const token = await createCompletedToken("GRAD");
+    const curveBefore = await program.account.tokenBondingCurve.fetch(token.curve);
+    const migrated = findEvent(await cpiEvents(await migrate(token)), "CurveMigrated");
+    assert.ok(migrated, "Migration should emit a CurveMigrated event");
+
+    const { pool, lpMint } = poolAccountsFor(token);
+    const poolState = await program.account.liquidityPool.fetch(pool);
+
+    assert.ok(migrated.data.mint.equals(token.mint.publicKey));
+    assert.ok(migrated.data.pool.equals(pool));
+    assert.ok(migrated.data.lpMint.equals(lpMint));
+    assert.equal(migrated.data.solAmount.toString(), curveBefore.realSolReserves.toString());
+    assert.equal(migrated.data.tokenAmount.toString(), curveBefore.realTokenReserves.toString());
+    assert.equal(migrated.data.lockedLpSupply.toString(), poolState.lockedLpSupply.toString());
+    assert.equal(migrated.data.poolFeeBps, poolState.feeBps);
+    assert.equal(migrated.data.realSolReserves.toNumber(), 0);
+    assert.equal(migrated.data.realTokenReserves.toNumber(), 0);
+    assert.equal(migrated.data.virtualSolReserves.toString(), curveBefore.virtualSolReserves.toString());
+
+    console.log(" Trade And Migration Events Decoded From CPI");
+    console.log("   Tokens:", trade.data.tokenAmount.toString());
+    console.log("   Migrated SOL:", migrated.data.solAmount.toString());
+  });
+
+  it("32. Distribute Crank Pays Several Depositors Exactly Like A Self-Claim", async () => {
+    const sol = (amount: number) => new anchor.BN(amount * LAMPORTS_PER_SOL);
+
+    // Two fresh wallets that have never held the token, so they have no ATA
+    const depositors = [Keypair.generate(), Keypair.generate()];
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        ...depositors.map((depositor) =>
+          SystemProgram.transfer({
+            fromPubkey: admin.publicKey,
+            toPubkey: depositor.publicKey,
+            lamports: 0.1 * LAMPORTS_PER_SOL,
+          })
+        )
+      )
+    );
+
+    // Oversubscribed 2x, so every claim also refunds half the deposit
+    const token = await createTestToken({
+      symbol: "DIST",
+      alphaDurationSeconds: 8,
+      alphaVault: { hardCap: sol(0.06) },
+    });
+    const deposits = [
+      { wallet: userB, amount: sol(0.03) },
+      { wallet: depositors[0], amount: sol(0.03) },
+      { wallet: depositors[1], amount: sol(0.06) },
+    ].map(({ wallet, amount }) => ({
+      wallet,
+      amount,
+      deposit: alphaDepositPda(wallet.publicKey, token.mint.publicKey),
+      tokenAccount: tokenAccountFor(wallet.publicKey, token.mint.publicKey),
+    }));
+    const [selfClaimer, crankedTwin, crankedDouble] = deposits;
+
+    for (const { wallet, amount, deposit } of deposits) {
+      await program.methods
+        .depositAlpha(amount, null)
+        .accounts({
+          user: wallet.publicKey,
+          bondingCurve: token.curve,
+          mint: token.mint.publicKey,
+          platformConfig: platformConfig,
+          userDeposit: deposit,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wallet])
+        .rpc();
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 9000));
+    await program.methods
+      .finalizeAlpha()
+      .accounts({ bondingCurve: token.curve, mint: token.mint.publicKey })
+      .rpc();
+
+    // User B self-claims first
+    await program.methods
+      .claimAlpha(false)
+      .accounts({
+        claimer: userB.publicKey,
+        bondingCurve: token.curve,
+        mint: token.mint.publicKey,
+        platformConfig: platformConfig,
+        userDeposit: selfClaimer.deposit,
+        bondingCurveTokenAccount: token.vault,
+        claimerTokenAccount: selfClaimer.tokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+    const selfClaimed = await program.account.userAlphaDeposit.fetch(selfClaimer.deposit);
+
+    for (const { tokenAccount } of [crankedTwin, crankedDouble]) {
+      assert.isNull(await provider.connection.getAccountInfo(tokenAccount), "Depositor should have no ATA yet");
+    }
+    const lamportsBefore = await Promise.all(
+      [crankedTwin, crankedDouble].map(({ wallet }) => provider.connection.getBalance(wallet.publicKey))
+    );
+
+    // One batch: a new depositor, the already-claimed one, another new one,
+    // and the first again. Only two payouts should happen.
+    const triple = ({ wallet, deposit, tokenAccount }: typeof selfClaimer) => [
+      { pubkey: deposit, isWritable: true, isSigner: false },
+      { pubkey: wallet.publicKey, isWritable: true, isSigner: false },
+      { pubkey: tokenAccount, isWritable: true, isSigner: false },
+    ];
+    await program.methods
+      .distributeAlpha()
+      .accounts({
+        payer: admin.publicKey,
+        bondingCurve: token.curve,
+        mint: token.mint.publicKey,
+        platformConfig: platformConfig,
+        bondingCurveTokenAccount: token.vault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        ...triple(crankedTwin),
+        ...triple(selfClaimer),
+        ...triple(crankedDouble),
+        ...triple(crankedTwin),
+      ])
+      .rpc();
+
+    const curve = await program.account.tokenBondingCurve.fetch(token.curve);
+    const tokenBalance = async (tokenAccount: PublicKey) =>
+      (await provider.connection.getTokenAccountBalance(tokenAccount)).value.amount;
+
+    // Same deposit as User B, so the crank must pay exactly what claim_alpha did
+    const twin = await program.account.userAlphaDeposit.fetch(crankedTwin.deposit);
+    assert.isTrue(twin.claimed);
+    assert.equal(twin.tokensClaimed.toString(), selfClaimed.tokensClaimed.toString());
+    assert.equal(twin.solRefunded.toString(), selfClaimed.solRefunded.toString());
+    assert.equal(await tokenBalance(crankedTwin.tokenAccount), selfClaimed.tokensClaimed.toString());
+    assert.equal(
+      (await provider.connection.getBalance(crankedTwin.wallet.publicKey)) - lamportsBefore[0],
+      twin.solRefunded.toNumber(),
+      "Listing the depositor twice should not pay twice"
+    );
+
+    // Twice the deposit: the same pro-rata formula claim_alpha uses
+    const double = await program.account.userAlphaDeposit.fetch(crankedDouble.deposit);
+    const expectedAllocation = crankedDouble.amount.mul(curve.totalAlphaToken).div(curve.totalAlphaSol);
+    const expectedRefund = crankedDouble.amount
+      .mul(curve.totalAlphaSol.sub(curve.alphaSolUsed))
+      .div(curve.totalAlphaSol);
+    assert.isTrue(double.claimed);
+    assert.equal(double.tokensClaimed.toString(), expectedAllocation.toString());
+    assert.equal(double.solRefunded.toString(), expectedRefund.toString());
+    assert.equal(await tokenBalance(crankedDouble.tokenAccount), expectedAllocation.toString());
+    assert.equal(
+      (await provider.connection.getBalance(crankedDouble.wallet.publicKey)) - lamportsBefore[1],
+      expectedRefund.toNumber()
+    );
+
+    // The self-claimer was skipped
+    const selfAfter = await program.account.userAlphaDeposit.fetch(selfClaimer.deposit);
+    assert.equal(selfAfter.tokensClaimed.toString(), selfClaimed.tokensClaimed.toString());
+    assert.equal(await tokenBalance(selfClaimer.tokenAccount), selfClaimed.tokensClaimed.toString());
+
+    console.log(" Crank Created ATAs And Matched Self-Claims");
+    console.log("   Per 0.03 SOL:", selfClaimed.tokensClaimed.toString(), "tokens");
+  });
+
   // Final state verification
   after(async () => {
     console.log(" FINAL STATE SUMMARY");